@@ -0,0 +1,252 @@
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use utils::{AnySolution, Part};
+
+const HEADER: &str = "commit,timestamp,day,phase,iterations,mean_ns,min_ns,stddev_ns";
+
+/// Measured step of a day, parsing is timed separately from the parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [
+        Phase::Parse,
+        Phase::Solve(Part::First),
+        Phase::Solve(Part::Second),
+    ];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub min: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Mean, minimum and population standard deviation of the samples in nanoseconds
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let samples = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let count = samples.len().max(1) as f64;
+        let mean = samples.iter().sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Stats {
+            mean,
+            min: samples.iter().copied().reduce(f64::min).unwrap_or_default(),
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Timings of one phase of one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub commit: String,
+    pub timestamp: u64,
+    pub day: u8,
+    pub phase: String,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{:.0},{:.0},{:.0}",
+            self.commit,
+            self.timestamp,
+            self.day,
+            self.phase,
+            self.iterations,
+            self.stats.mean,
+            self.stats.min,
+            self.stats.stddev
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let fields = line.split(',').collect::<Vec<_>>();
+        let [commit, timestamp, day, phase, iterations, mean, min, stddev] = fields[..] else {
+            return None;
+        };
+        Some(Record {
+            commit: commit.to_string(),
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            phase: phase.to_string(),
+            iterations: iterations.parse().ok()?,
+            stats: Stats {
+                mean: mean.parse().ok()?,
+                min: min.parse().ok()?,
+                stddev: stddev.parse().ok()?,
+            },
+        })
+    }
+}
+
+/// Runs parse and both parts `iterations` times, returns the stats in [`Phase::ALL`] order
+pub fn measure(
+    day: &dyn AnySolution,
+    input: &str,
+    iterations: usize,
+) -> Result<[Stats; 3], String> {
+    let mut samples = [vec![], vec![], vec![]];
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = day
+            .parse_input(input)
+            .map_err(|error| format!("invalid input, {}", error))?;
+        samples[0].push(start.elapsed());
+        for (index, part) in Part::ALL.into_iter().enumerate() {
+            let start = Instant::now();
+            std::hint::black_box(
+                day.solve(parsed.as_ref(), part)
+                    .map_err(|error| format!("part {}: {}", part, error))?,
+            );
+            samples[index + 1].push(start.elapsed());
+        }
+    }
+    Ok(samples.map(|samples| Stats::from_samples(&samples)))
+}
+
+/// Commit of the workspace as given by `git describe`, `-dirty` marks uncommitted changes
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+/// Appends records to the CSV file, the header is written when the file is new
+pub fn save(path: &Path, records: &[Record]) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Latest records of the commit starting with `commit` (all commits when `None`), malformed lines are ignored
+pub fn load(path: &Path, commit: Option<&str>) -> io::Result<Vec<Record>> {
+    let records = fs::read_to_string(path)?
+        .lines()
+        .skip(1)
+        .filter_map(Record::from_csv)
+        .filter(|record| commit.is_none_or(|commit| record.commit.starts_with(commit)))
+        .collect::<Vec<_>>();
+    let mut latest: Vec<Record> = vec![];
+    for record in records.into_iter().rev() {
+        if !latest
+            .iter()
+            .any(|known| known.day == record.day && known.phase == record.phase)
+        {
+            latest.push(record);
+        }
+    }
+    latest.reverse();
+    Ok(latest)
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{:.0} ns", n),
+        n if n < 1e6 => format!("{:.1} µs", n / 1e3),
+        n if n < 1e9 => format!("{:.2} ms", n / 1e6),
+        n => format!("{:.2} s", n / 1e9),
+    }
+}
+
+/// Relative change of the mean against the baseline, positive is slower
+pub fn format_change(current: &Stats, baseline: &Stats) -> String {
+    if baseline.mean == 0.0 {
+        return "-".to_string();
+    }
+    format!("{:+.1}%", (current.mean / baseline.mean - 1.0) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(
+            stats,
+            Stats {
+                mean: 5.0,
+                min: 2.0,
+                stddev: 2.0
+            }
+        );
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let record = Record {
+            commit: "abc1234".to_string(),
+            timestamp: 1733000000,
+            day: 6,
+            phase: Phase::Solve(Part::Second).to_string(),
+            iterations: 10,
+            stats: Stats {
+                mean: 1500.0,
+                min: 1200.0,
+                stddev: 80.0,
+            },
+        };
+        assert_eq!(Record::from_csv(&record.to_csv()), Some(record));
+        assert_eq!(Record::from_csv(HEADER), None);
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_nanos(950.0), "950 ns");
+        assert_eq!(format_nanos(12_345.0), "12.3 µs");
+        assert_eq!(format_nanos(4_560_000.0), "4.56 ms");
+        let baseline = Stats::from_samples(&[Duration::from_nanos(200)]);
+        let current = Stats::from_samples(&[Duration::from_nanos(150)]);
+        assert_eq!(format_change(&current, &baseline), "-25.0%");
+    }
+}
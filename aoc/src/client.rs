@@ -0,0 +1,262 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use utils::Part;
+
+use crate::submit::Verdict;
+
+/// Session cookie of a logged in adventofcode.com user
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Server the client talks to, a local stand-in can be used instead of adventofcode.com
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = "github.com/JKubovy/Advent-of-Code-2024 runner";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(
+                    f,
+                    "set {} to the session cookie of adventofcode.com",
+                    SESSION_ENV
+                )
+            }
+            ClientError::Status(status, body) => {
+                write!(f, "server responded with {}: {}", status, body.trim())
+            }
+            ClientError::Transport(error) => write!(f, "request failed: {}", error),
+            ClientError::Io(path, error) => write!(f, "cannot write {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(error) => ClientError::Transport(error.to_string()),
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Client configured by [`SESSION_ENV`] and [`BASE_URL_ENV`]
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::MissingSession)?;
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        self.agent
+            .get(&format!("{}/input", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))
+    }
+
+    /// Posts the answer of one part and parses the response page
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let page = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))?;
+        Ok(Verdict::parse(&page))
+    }
+
+    /// Downloads the input into `path` unless it is already there, inputs never change so they are fetched once.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.input(day)?;
+        let io_error = |error| ClientError::Io(path.to_path_buf(), error);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(path, input).map_err(io_error)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+/// Local stand-in of adventofcode.com answering every request with the next canned response
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Request line, headers and body of a received request
+    #[derive(Debug, Clone, Default)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|header| {
+                let (key, value) = header.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then_some(value.trim())
+            })
+        }
+    }
+
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request = Request::default();
+                reader.read_line(&mut request.line).unwrap();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    request.headers.push(header.trim().to_string());
+                }
+                let length = request
+                    .header("Content-Length")
+                    .and_then(|length| length.parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.body = String::from_utf8_lossy(&body).to_string();
+                received.lock().unwrap().push(request);
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+        Server { url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("aoc-client-{}-{}", std::process::id(), name))
+            .join("input.txt")
+    }
+
+    #[test]
+    fn fetches_input_once() {
+        let server = mock::serve(vec![(200, "1 2\n3 4\n")]);
+        let client = Client::new(&server.url, "secret");
+        let path = temp_path("fetch");
+        assert_eq!(client.fetch_input(6, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(client.fetch_input(6, &path).unwrap(), Fetched::Cached);
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].line.starts_with("GET /2024/day/6/input "));
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let server = mock::serve(vec![(400, "Please log in")]);
+        let client = Client::new(&server.url, "expired");
+        let path = temp_path("error");
+        let error = client.fetch_input(1, &path).unwrap_err();
+        assert!(matches!(error, ClientError::Status(400, ref body) if body == "Please log in"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn submits_answer() {
+        let server = mock::serve(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = Client::new(&server.url, "secret");
+        assert_eq!(
+            client.submit(7, Part::Second, "11387").unwrap(),
+            Verdict::Correct
+        );
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].line.starts_with("POST /2024/day/7/answer "));
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=11387");
+    }
+}
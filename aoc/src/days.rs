@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+use utils::AnySolution;
+
+pub const DAYS: &[&dyn AnySolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner is a workspace member")
+}
+
+pub fn crate_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{:02}", day))
+}
@@ -0,0 +1,202 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use utils::answers::Answers;
+use utils::Part;
+
+/// Worked example of one part found in the puzzle description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Text between `start` and `end` markers, with the position right after the block
+fn blocks<'a>(html: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut found = vec![];
+    let mut offset = 0;
+    while let Some(begin) = html[offset..].find(start) {
+        let content = offset + begin + start.len();
+        let Some(length) = html[content..].find(end) else {
+            break;
+        };
+        offset = content + length + end.len();
+        found.push((offset, &html[content..content + length]));
+    }
+    found
+}
+
+/// Removes markup (like `<em>` highlights) and decodes the entities used by the puzzle pages
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Examples of the parts described on the page, one `<article>` per unlocked part
+///
+/// The answer of a part is the last emphasised code (`<code><em>`) of its article and the example is
+/// the last `<pre><code>` block before it, the second part reuses the first example when it has none.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (part, (_, article)) in Part::ALL
+        .into_iter()
+        .zip(blocks(html, "<article", "</article>"))
+    {
+        let Some(&(answer_end, answer)) = blocks(article, "<code><em>", "</em></code>").last()
+        else {
+            continue;
+        };
+        let input = blocks(article, "<pre><code>", "</code></pre>")
+            .into_iter()
+            .filter(|&(end, _)| end < answer_end)
+            .map(|(_, input)| text(input))
+            .next_back()
+            .or_else(|| examples.last().map(|example| example.input.clone()));
+        if let Some(input) = input {
+            examples.push(Example {
+                part,
+                input,
+                answer: text(answer),
+            });
+        }
+    }
+    examples
+}
+
+/// Name of a fixture in `inputs` with the same lines as `input`, otherwise the first free `exampleN`
+fn fixture_name(inputs_dir: &Path, input: &str) -> io::Result<(String, bool)> {
+    let mut names = vec![];
+    if inputs_dir.exists() {
+        for entry in fs::read_dir(inputs_dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if path.extension().is_none_or(|extension| extension != "txt") || name == "input" {
+                continue;
+            }
+            if fs::read_to_string(&path)?.lines().eq(input.lines()) {
+                return Ok((name.to_string(), false));
+            }
+            names.push(name.to_string());
+        }
+    }
+    let name = (1..)
+        .map(|index| format!("example{}", index))
+        .find(|name| !names.contains(name))
+        .expect("there is always a free name");
+    Ok((name, true))
+}
+
+/// Writes new fixtures and records the answers, returns `(fixture, example)` pairs
+pub fn save(
+    crate_dir: &Path,
+    answers: &mut Answers,
+    examples: Vec<Example>,
+) -> io::Result<Vec<(String, Example)>> {
+    let inputs_dir = crate_dir.join("inputs");
+    let mut saved = vec![];
+    for example in examples {
+        let (name, is_new) = fixture_name(&inputs_dir, &example.input)?;
+        if is_new {
+            fs::create_dir_all(&inputs_dir)?;
+            fs::write(inputs_dir.join(format!("{}.txt", name)), &example.input)?;
+        }
+        answers.record(&name, example.part, &example.answer);
+        saved.push((name, example));
+    }
+    Ok(saved)
+}
+
+/// Integration test checking the recorded answers of the given fixtures
+pub fn tests_source(day: u8, fixtures: &[(String, Part)]) -> String {
+    let solution = format!("Day{:02}", day);
+    let mut source = format!(
+        "// Generated by `aoc extract`, changes are overwritten.\nuse day{:02}::{};\nuse utils::Part;\n",
+        day, solution
+    );
+    for (name, part) in fixtures {
+        let (suffix, variant) = match part {
+            Part::First => ("first_part", "First"),
+            Part::Second => ("second_part", "Second"),
+        };
+        source += &format!(
+            "\n#[test]\nfn {}_{}() {{\n    utils::check_answer!({}, \"{}\", Part::{});\n}}\n",
+            name, suffix, solution, name, variant
+        );
+    }
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Lists like <code>a &lt; b</code> are compared.</p>
+<pre><code>3   <em>4</em>
+2   5
+</code></pre>
+<p>In the example above, this is <code>2 + 1</code>, a total distance of <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>2057374</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, the similarity score is <code><em>31</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_examples() {
+        let examples = examples(PAGE);
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    part: Part::First,
+                    input: "3   4\n2   5\n".to_string(),
+                    answer: "11".to_string(),
+                },
+                Example {
+                    part: Part::Second,
+                    input: "3   4\n2   5\n".to_string(),
+                    answer: "31".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn only_first_part() {
+        let page = PAGE.split("<p>Your puzzle").next().unwrap();
+        assert_eq!(examples(page).len(), 1);
+        assert_eq!(text("a &lt;<em>b</em>&amp;&gt; c"), "a <b&> c");
+    }
+
+    #[test]
+    fn generated_tests() {
+        let source = tests_source(3, &[("example1".to_string(), Part::Second)]);
+        assert!(source.contains("use day03::Day03;"));
+        assert!(source.contains("fn example1_second_part() {"));
+        assert!(source.contains("utils::check_answer!(Day03, \"example1\", Part::Second);"));
+    }
+}
@@ -0,0 +1,818 @@
+mod bench;
+mod client;
+mod days;
+mod extract;
+mod pool;
+mod report;
+mod scaffold;
+mod submit;
+mod table;
+
+use std::any::Any;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use bench::{Phase, Record};
+use clap::{Parser, Subcommand};
+use client::{Client, Fetched};
+use days::DAYS;
+use report::{Format, RunResult, Status};
+use submit::{Submission, SubmissionLog, Verdict};
+use table::Table;
+use utils::answers::{self, Answers, PRIVATE_INPUT};
+use utils::input::{self, InputError};
+use utils::{AnySolution, InputSource, Part};
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (`6`), a range of days (`1..5`) or all registered days
+    Run {
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Run only the given part
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Input file (`-` for stdin) used instead of `$AOC_INPUT_DIR/dayXX.txt` or `dayXX/inputs/input.txt`
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Output format, answers of the personal input are checked against `dayXX/answers.txt`
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Number of days and parts solved at once, defaults to the number of cores
+        #[arg(short, long)]
+        jobs: Option<usize>,
+        /// Add how each answer was found, for the days that can tell (table and JSON output)
+        #[arg(short, long)]
+        explain: bool,
+    },
+    /// Time parsing and both parts of the selected days and store the results
+    Bench {
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Number of measured runs of every phase
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Input file (`-` for stdin), only for a single day
+        #[arg(short, long)]
+        input: Option<String>,
+        /// CSV file the results are appended to
+        #[arg(short, long, default_value = "bench.csv")]
+        output: PathBuf,
+        /// Commit (or its prefix) from the output file to compare against
+        #[arg(short, long)]
+        baseline: Option<String>,
+    },
+    /// Check the selected days against the answers recorded in `dayXX/answers.txt`
+    Verify {
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+    },
+    /// Record an answer in `dayXX/answers.txt`, the answer is computed when not given
+    Record {
+        day: u8,
+        /// Record only the given part, required with `--answer`
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Input name, `input` is the personal input and other names are `inputs/<name>.txt`
+        #[arg(short, long, default_value = PRIVATE_INPUT)]
+        name: String,
+        /// Known correct answer to store instead of the computed one
+        #[arg(short, long, requires = "part")]
+        answer: Option<String>,
+    },
+    /// Solve one part of the personal input and submit the answer to adventofcode.com
+    Submit {
+        day: u8,
+        #[arg(value_parser = parse_part)]
+        part: Part,
+    },
+    /// Create the `dayXX` crate from `template/` and register it in the runner
+    New { day: u8 },
+    /// Turn examples of a saved puzzle page into fixtures, answers and `dayXX/tests/examples.rs`
+    Extract {
+        day: u8,
+        /// Puzzle description saved from the browser
+        page: PathBuf,
+    },
+    /// Download personal inputs (all registered days by default) using `AOC_SESSION`, cached inputs are kept
+    Fetch {
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+    },
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("`{}` is not a day number", day))
+    };
+    match value
+        .split_once("..=")
+        .or_else(|| value.split_once(".."))
+        .or_else(|| value.split_once('-'))
+    {
+        Some((from, to)) => match (parse(from)?, parse(to)?) {
+            (from, to) if from > to => Err(format!("`{}` ends before it starts", value)),
+            (from, to) => Ok(from..=to),
+        },
+        None => {
+            let day = parse(value)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .and_then(|part| Part::try_from(part).ok())
+        .ok_or_else(|| format!("`{}` is not a part, expected 1 or 2", value))
+}
+
+/// Registered days within `days`, `None` (after reporting why) when the selection is unusable
+fn select_days(
+    days: Option<&RangeInclusive<u8>>,
+    input: Option<&str>,
+) -> Option<Vec<&'static dyn AnySolution>> {
+    let selected = DAYS
+        .iter()
+        .copied()
+        .filter(|day| days.is_none_or(|days| days.contains(&day.day())))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        eprintln!("No registered day matches the selection");
+        return None;
+    }
+    if input.is_some() && selected.len() > 1 {
+        eprintln!("An input can be given only when running a single day");
+        return None;
+    }
+    Some(selected)
+}
+
+/// Explicit input or the personal one of `day`, the error is the message to print
+fn read_input(day: &dyn AnySolution, input: Option<&str>) -> Result<String, String> {
+    InputSource::resolve(day.day(), input, &days::crate_dir(day.day()))
+        .read()
+        .map_err(|error| format!("Skipping day {}: {}", day.day(), error))
+}
+
+/// Parsed input of a day with what is needed to report its answers
+struct Loaded {
+    day: &'static dyn AnySolution,
+    parsed: Box<dyn Any + Send + Sync>,
+    input_hash: u64,
+    answers: Option<Answers>,
+}
+
+fn run(
+    days: Option<RangeInclusive<u8>>,
+    part: Option<Part>,
+    input: Option<String>,
+    format: Format,
+    jobs: usize,
+    explain: bool,
+) -> ExitCode {
+    let Some(selected) = select_days(days.as_ref(), input.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+    let loaded = pool::parallel_map(&selected, jobs, |&day| {
+        let data = read_input(day, input.as_deref())?;
+        let parsed = day
+            .parse_input(&data)
+            .map_err(|error| format!("Skipping day {}: invalid input, {}", day.day(), error))?;
+        // an explicit input or one from `AOC_INPUT_DIR` is not the one the answers were recorded for
+        let answers = match input {
+            Some(_) => None,
+            None if input::input_dir_is_set() => None,
+            None => Answers::load(&days::crate_dir(day.day()))
+                .inspect_err(|error| eprintln!("Day {}: {}", day.day(), error))
+                .ok(),
+        };
+        Ok(Loaded {
+            day,
+            parsed,
+            input_hash: report::input_hash(&data),
+            answers,
+        })
+    });
+    let mut status = ExitCode::SUCCESS;
+    let loaded = loaded
+        .into_iter()
+        .filter_map(|loaded: Result<Loaded, String>| {
+            loaded
+                .inspect_err(|error| {
+                    eprintln!("{}", error);
+                    status = ExitCode::FAILURE;
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    let tasks = loaded
+        .iter()
+        .flat_map(|loaded| {
+            Part::ALL
+                .into_iter()
+                .filter(|current| part.is_none_or(|part| part == *current))
+                .map(move |current| (loaded, current))
+        })
+        .collect::<Vec<_>>();
+    let results = pool::parallel_map(&tasks, jobs, |&(loaded, current)| {
+        let start = Instant::now();
+        let answer = loaded
+            .day
+            .solve(loaded.parsed.as_ref(), current)
+            .map_err(|error| format!("Day {} part {}: {}", loaded.day.day(), current, error))?;
+        let elapsed = start.elapsed();
+        let expected = loaded
+            .answers
+            .as_ref()
+            .and_then(|answers| answers.get(PRIVATE_INPUT, current));
+        let answer_status = Status::new(&answer, expected);
+        let mut result = RunResult::new(
+            loaded.day.day(),
+            loaded.day.title(),
+            current,
+            answer,
+            elapsed,
+            loaded.input_hash,
+            answer_status,
+        );
+        if explain {
+            result.explanation = loaded.day.explain(loaded.parsed.as_ref(), current);
+        }
+        Ok(result)
+    });
+    let results = results
+        .into_iter()
+        .filter_map(|result: Result<RunResult, String>| {
+            result
+                .inspect_err(|error| {
+                    eprintln!("{}", error);
+                    status = ExitCode::FAILURE;
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    print!("{}", report::render(&results, format));
+    status
+}
+
+fn bench(
+    days: Option<RangeInclusive<u8>>,
+    iterations: usize,
+    input: Option<String>,
+    output: &Path,
+    baseline: Option<String>,
+) -> ExitCode {
+    let Some(selected) = select_days(days.as_ref(), input.as_deref()) else {
+        return ExitCode::FAILURE;
+    };
+    let baseline = match baseline {
+        Some(commit) => match bench::load(output, Some(&commit)) {
+            Ok(records) if !records.is_empty() => records,
+            Ok(_) => {
+                eprintln!("No results of commit `{}` in {}", commit, output.display());
+                return ExitCode::FAILURE;
+            }
+            Err(error) => {
+                eprintln!("Cannot read {}: {}", output.display(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => vec![],
+    };
+    let mut header = vec!["Day", "Title", "Phase", "Mean", "Min", "Std dev"];
+    if !baseline.is_empty() {
+        header.push("Change");
+    }
+    let mut status = ExitCode::SUCCESS;
+    let mut table = Table::new(&header);
+    let mut records = vec![];
+    let (commit, timestamp) = (bench::current_commit(), bench::timestamp());
+    for day in selected {
+        let input = match read_input(day, input.as_deref()) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}", error);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let measured = match bench::measure(day, &input, iterations) {
+            Ok(measured) => measured,
+            Err(error) => {
+                eprintln!("Skipping day {}: {}", day.day(), error);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        for (phase, stats) in Phase::ALL.into_iter().zip(measured) {
+            let mut row = vec![
+                day.day().to_string(),
+                day.title().to_string(),
+                phase.to_string(),
+                bench::format_nanos(stats.mean),
+                bench::format_nanos(stats.min),
+                bench::format_nanos(stats.stddev),
+            ];
+            if !baseline.is_empty() {
+                row.push(
+                    baseline
+                        .iter()
+                        .find(|record| record.day == day.day() && record.phase == phase.to_string())
+                        .map_or("-".to_string(), |record| {
+                            bench::format_change(&stats, &record.stats)
+                        }),
+                );
+            }
+            table.push(row);
+            records.push(Record {
+                commit: commit.clone(),
+                timestamp,
+                day: day.day(),
+                phase: phase.to_string(),
+                iterations,
+                stats,
+            });
+        }
+    }
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+    if records.is_empty() {
+        return status;
+    }
+    if let Err(error) = bench::save(output, &records) {
+        eprintln!("Cannot write {}: {}", output.display(), error);
+        return ExitCode::FAILURE;
+    }
+    status
+}
+
+/// Check of one recorded answer by `aoc verify`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    Wrong,
+    /// Input that cannot be read or parsed, or a part that fails
+    Failed(String),
+    /// Answer that is not checked, with the reason
+    Skipped(String),
+}
+
+fn verify(days: Option<RangeInclusive<u8>>) -> ExitCode {
+    let Some(selected) = select_days(days.as_ref(), None) else {
+        return ExitCode::FAILURE;
+    };
+    let mut status = ExitCode::SUCCESS;
+    let mut table = Table::new(&["Day", "Input", "Part", "Expected", "Actual", "Status"]);
+    for day in selected {
+        let crate_dir = days::crate_dir(day.day());
+        let answers = match Answers::load(&crate_dir) {
+            Ok(answers) => answers,
+            Err(error) => {
+                eprintln!("Skipping day {}: {}", day.day(), error);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if answers.entries().is_empty() {
+            eprintln!("Day {} has no recorded answers", day.day());
+        }
+        let mut parsed: Vec<(&str, Result<_, Outcome>)> = vec![];
+        for answer in answers.entries() {
+            if !parsed.iter().any(|(name, _)| *name == answer.input) {
+                let input = match answer.input.as_str() {
+                    PRIVATE_INPUT if input::input_dir_is_set() => Err(Outcome::Skipped(format!(
+                        "{} may hold someone else's input",
+                        input::INPUT_DIR_ENV
+                    ))),
+                    name => answers::input_source(day.day(), name, &crate_dir)
+                        .read()
+                        .map_err(|error| match error {
+                            InputError::NotFound(path) if name == PRIVATE_INPUT => {
+                                Outcome::Skipped(format!("{} is not checked out", path.display()))
+                            }
+                            error => Outcome::Failed(error.to_string()),
+                        }),
+                }
+                .and_then(|input| {
+                    day.parse_input(&input)
+                        .map_err(|error| Outcome::Failed(format!("invalid input, {}", error)))
+                });
+                parsed.push((&answer.input, input));
+            }
+            let (_, input) = parsed
+                .iter()
+                .find(|(name, _)| *name == answer.input)
+                .expect("input was parsed above");
+            let (actual, outcome) = match input {
+                Ok(input) => match day.solve(input.as_ref(), answer.part) {
+                    Ok(actual) if actual == answer.expected => (actual, Outcome::Passed),
+                    Ok(actual) => (actual, Outcome::Wrong),
+                    Err(error) => (String::new(), Outcome::Failed(error)),
+                },
+                Err(outcome) => (String::new(), outcome.clone()),
+            };
+            let result = match outcome {
+                Outcome::Passed => "ok",
+                Outcome::Wrong => {
+                    status = ExitCode::FAILURE;
+                    "WRONG"
+                }
+                Outcome::Failed(error) => {
+                    eprintln!(
+                        "Day {} `{}` part {}: {}",
+                        day.day(),
+                        answer.input,
+                        answer.part,
+                        error
+                    );
+                    status = ExitCode::FAILURE;
+                    "error"
+                }
+                Outcome::Skipped(reason) => {
+                    eprintln!(
+                        "Skipping day {} `{}` part {}: {}",
+                        day.day(),
+                        answer.input,
+                        answer.part,
+                        reason
+                    );
+                    "skipped"
+                }
+            };
+            table.push(vec![
+                day.day().to_string(),
+                answer.input.clone(),
+                answer.part.to_string(),
+                answer.expected.clone(),
+                actual,
+                result.to_string(),
+            ]);
+        }
+    }
+    if !table.is_empty() {
+        print!("{}", table);
+    }
+    status
+}
+
+fn record(day: u8, part: Option<Part>, name: String, answer: Option<String>) -> ExitCode {
+    let Some(&solution) = DAYS.iter().find(|solution| solution.day() == day) else {
+        eprintln!("Day {} is not registered", day);
+        return ExitCode::FAILURE;
+    };
+    let crate_dir = days::crate_dir(day);
+    let mut answers = match Answers::load(&crate_dir) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|current| part.is_none_or(|part| part == *current));
+    let recorded = match answer {
+        Some(answer) => parts.map(|part| (part, answer.clone())).collect::<Vec<_>>(),
+        None => {
+            let input = match answers::input_source(day, &name, &crate_dir).read() {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let input = match solution.parse_input(&input) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("Invalid input: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+            match parts
+                .map(|part| Ok((part, solution.solve(input.as_ref(), part)?)))
+                .collect::<Result<Vec<_>, String>>()
+            {
+                Ok(recorded) => recorded,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    for (part, answer) in recorded {
+        match answers.record(&name, part, &answer) {
+            Some(previous) => println!(
+                "Day {} part {} of `{}`: {} (was {})",
+                day, part, name, answer, previous
+            ),
+            None => println!("Day {} part {} of `{}`: {}", day, part, name, answer),
+        }
+    }
+    if let Err(error) = answers.save() {
+        eprintln!("{}", error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn fetch(days: Option<RangeInclusive<u8>>) -> ExitCode {
+    let days = match days {
+        Some(days) if *days.start() < 1 || *days.end() > 25 => {
+            eprintln!("Advent of Code has days 1 to 25");
+            return ExitCode::FAILURE;
+        }
+        Some(days) => days.collect::<Vec<_>>(),
+        None => DAYS.iter().map(|day| day.day()).collect(),
+    };
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let path = input::default_path(day, &days::crate_dir(day));
+        match client.fetch_input(day, &path) {
+            Ok(Fetched::Downloaded) => println!("Day {}: saved to {}", day, path.display()),
+            Ok(Fetched::Cached) => println!("Day {}: already cached in {}", day, path.display()),
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Local log of submitted answers, wrong guesses are never submitted again
+const SUBMISSIONS_FILE: &str = "submissions.txt";
+
+fn submit(day: u8, part: Part) -> ExitCode {
+    let Some(&solution) = DAYS.iter().find(|solution| solution.day() == day) else {
+        eprintln!("Day {} is not registered", day);
+        return ExitCode::FAILURE;
+    };
+    let crate_dir = days::crate_dir(day);
+    let mut answers = match Answers::load(&crate_dir) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match read_input(solution, None) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match solution.parse_input(&input) {
+        Ok(input) => match solution.solve(input.as_ref(), part) {
+            Ok(answer) => answer,
+            Err(error) => {
+                eprintln!("Day {} part {}: {}", day, part, error);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(error) => {
+            eprintln!("Invalid input: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(known) = answers.get(PRIVATE_INPUT, part) {
+        println!(
+            "Day {} part {} is already solved with {}, computed {}",
+            day, part, known, answer
+        );
+        return if known == answer {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+    let log_path = days::workspace_dir().join(SUBMISSIONS_FILE);
+    let mut log = match SubmissionLog::load(&log_path) {
+        Ok(log) => log,
+        Err(error) => {
+            eprintln!("Cannot read {}: {}", log_path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let now = bench::timestamp();
+    if let Some(reason) = log.check(day, part, &answer, now) {
+        eprintln!("Not submitting day {} part {}: {}", day, part, reason);
+        return ExitCode::FAILURE;
+    }
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let verdict = match client.submit(day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+    if let Err(error) = log.append(Submission::new(now, day, part, &answer, &verdict)) {
+        eprintln!("Cannot write {}: {}", log_path.display(), error);
+    }
+    if verdict != Verdict::Correct {
+        return ExitCode::FAILURE;
+    }
+    answers.record(PRIVATE_INPUT, part, &answer);
+    if let Err(error) = answers.save() {
+        eprintln!("{}", error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn new_day(day: u8) -> ExitCode {
+    if !(1..=25).contains(&day) {
+        eprintln!("Advent of Code has days 1 to 25");
+        return ExitCode::FAILURE;
+    }
+    let crate_dir = days::crate_dir(day);
+    if crate_dir.exists() {
+        eprintln!("{} already exists", crate_dir.display());
+        return ExitCode::FAILURE;
+    }
+    let workspace = days::workspace_dir();
+    if let Err(error) = scaffold::create(&workspace.join("template"), &crate_dir, day) {
+        eprintln!("Cannot create {}: {}", crate_dir.display(), error);
+        return ExitCode::FAILURE;
+    }
+    let runner = workspace.join("aoc");
+    let registrations: [(PathBuf, scaffold::Register); 2] = [
+        (runner.join("Cargo.toml"), scaffold::register_dependency),
+        (runner.join("src").join("days.rs"), scaffold::register_day),
+    ];
+    for (path, register) in registrations {
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) => {
+                eprintln!("Cannot read {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        };
+        let Some(content) = register(&content, day) else {
+            eprintln!("Day {} is already registered in {}", day, path.display());
+            continue;
+        };
+        if let Err(error) = std::fs::write(&path, content) {
+            eprintln!("Cannot write {}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    }
+    println!("Created {}, next steps:", crate_dir.display());
+    println!("  cargo run -p aoc -- fetch {}", day);
+    println!("  cargo run -p aoc -- extract {} <saved puzzle page>", day);
+    ExitCode::SUCCESS
+}
+
+fn extract(day: u8, page: &Path) -> ExitCode {
+    let crate_dir = days::crate_dir(day);
+    if !crate_dir.exists() {
+        eprintln!(
+            "{} does not exist, create the day first",
+            crate_dir.display()
+        );
+        return ExitCode::FAILURE;
+    }
+    let html = match std::fs::read_to_string(page) {
+        Ok(html) => html,
+        Err(error) => {
+            eprintln!("Cannot read {}: {}", page.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let examples = extract::examples(&html);
+    if examples.is_empty() {
+        eprintln!("No example with an answer found in {}", page.display());
+        return ExitCode::FAILURE;
+    }
+    let mut answers = match Answers::load(&crate_dir) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let saved = match extract::save(&crate_dir, &mut answers, examples) {
+        Ok(saved) => saved,
+        Err(error) => {
+            eprintln!("Cannot write fixtures: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    for (name, example) in &saved {
+        println!(
+            "Day {} part {}: inputs/{}.txt -> {}",
+            day, example.part, name, example.answer
+        );
+    }
+    if let Err(error) = answers.save() {
+        eprintln!("{}", error);
+        return ExitCode::FAILURE;
+    }
+    let fixtures = answers
+        .entries()
+        .iter()
+        .filter(|answer| answer.input != PRIVATE_INPUT)
+        .map(|answer| (answer.input.clone(), answer.part))
+        .collect::<Vec<_>>();
+    let tests = crate_dir.join("tests").join("examples.rs");
+    let written = std::fs::create_dir_all(crate_dir.join("tests"))
+        .and_then(|_| std::fs::write(&tests, extract::tests_source(day, &fixtures)));
+    if let Err(error) = written {
+        eprintln!("Cannot write {}: {}", tests.display(), error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+            jobs,
+            explain,
+        } => run(
+            days,
+            part,
+            input,
+            format,
+            jobs.unwrap_or_else(pool::default_jobs),
+            explain,
+        ),
+        Command::Bench {
+            days,
+            iterations,
+            input,
+            output,
+            baseline,
+        } => bench(days, iterations, input, &output, baseline),
+        Command::Verify { days } => verify(days),
+        Command::Record {
+            day,
+            part,
+            name,
+            answer,
+        } => record(day, part, name, answer),
+        Command::Submit { day, part } => submit(day, part),
+        Command::New { day } => new_day(day),
+        Command::Extract { day, page } => extract(day, &page),
+        Command::Fetch { days } => fetch(days),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!(parse_days("6"), Ok(6..=6));
+    }
+
+    #[test]
+    fn parse_day_range() {
+        assert_eq!(parse_days("1..5"), Ok(1..=5));
+        assert_eq!(parse_days("1..=5"), Ok(1..=5));
+        assert_eq!(parse_days("3-7"), Ok(3..=7));
+    }
+
+    #[test]
+    fn parse_invalid_day() {
+        assert!(parse_days("six").is_err());
+        assert!(parse_days("10..3").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Default worker count, one per available core
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Runs `task` for every item on `jobs` worker threads, the results keep the order of `items`
+pub fn parallel_map<T, R>(items: &[T], jobs: usize, task: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = task(item);
+                results.lock().expect("no worker panicked")[index] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .expect("no worker panicked")
+        .into_iter()
+        .map(|result| result.expect("every item was processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn keeps_order() {
+        let items = (0..50).collect::<Vec<u64>>();
+        let results = parallel_map(&items, 4, |&item| {
+            // later items finish first
+            thread::sleep(Duration::from_micros(50 - item));
+            item * 2
+        });
+        assert_eq!(
+            results,
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn uses_workers() {
+        let items = vec![(); 8];
+        let threads = parallel_map(&items, 4, |_| {
+            thread::sleep(Duration::from_millis(20));
+            thread::current().id()
+        });
+        assert!(threads.iter().collect::<HashSet<_>>().len() > 1);
+        assert!(parallel_map(&Vec::<u8>::new(), 4, |&item| item).is_empty());
+        assert_eq!(parallel_map(&[1, 2], 0, |&item| item), [1, 2]);
+    }
+}
@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use utils::Part;
+
+use crate::table::Table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Answer compared with `answers.txt`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Verified,
+    Unverified,
+    Mismatch,
+}
+
+impl Status {
+    pub fn new(answer: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Status::Verified,
+            Some(_) => Status::Mismatch,
+            None => Status::Unverified,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Verified => "verified",
+            Status::Unverified => "unverified",
+            Status::Mismatch => "mismatch",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RunResult {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    pub input_hash: String,
+    pub status: Status,
+    /// Filled by `--explain`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explanation: Vec<String>,
+}
+
+impl RunResult {
+    pub fn new(
+        day: u8,
+        title: &'static str,
+        part: Part,
+        answer: String,
+        elapsed: Duration,
+        input_hash: u64,
+        status: Status,
+    ) -> Self {
+        RunResult {
+            day,
+            title,
+            part: part.number(),
+            answer,
+            elapsed_ns: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+            input_hash: format!("{:016x}", input_hash),
+            status,
+            explanation: Vec::new(),
+        }
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and releases unlike `DefaultHasher`
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn render(results: &[RunResult], format: Format) -> String {
+    match format {
+        Format::Table => {
+            let mut table = Table::new(&["Day", "Title", "Part", "Answer", "Time", "Status"]);
+            for result in results {
+                table.push(vec![
+                    result.day.to_string(),
+                    result.title.to_string(),
+                    result.part.to_string(),
+                    result.answer.clone(),
+                    crate::bench::format_nanos(result.elapsed_ns as f64),
+                    result.status.as_str().to_string(),
+                ]);
+            }
+            let mut output = if table.is_empty() {
+                String::new()
+            } else {
+                table.to_string()
+            };
+            for result in results
+                .iter()
+                .filter(|result| !result.explanation.is_empty())
+            {
+                output += &format!("\nDay {} part {}:\n", result.day, result.part);
+                for line in &result.explanation {
+                    output += &format!("  {}\n", line);
+                }
+            }
+            output
+        }
+        Format::Json => {
+            serde_json::to_string_pretty(results).expect("results are serializable") + "\n"
+        }
+        Format::Csv => {
+            let mut csv = "day,title,part,answer,elapsed_ns,input_hash,status\n".to_string();
+            for result in results {
+                let fields = [
+                    result.day.to_string(),
+                    result.title.to_string(),
+                    result.part.to_string(),
+                    result.answer.clone(),
+                    result.elapsed_ns.to_string(),
+                    result.input_hash.clone(),
+                    result.status.as_str().to_string(),
+                ];
+                csv += &fields.map(|field| csv_field(&field)).join(",");
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result() -> RunResult {
+        RunResult::new(
+            1,
+            "Historian Hysteria",
+            Part::Second,
+            "31".to_string(),
+            Duration::from_micros(12),
+            input_hash("3   4\n"),
+            Status::new("31", Some("31")),
+        )
+    }
+
+    #[test]
+    fn hash_is_fnv1a() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn statuses() {
+        assert_eq!(Status::new("1", Some("1")), Status::Verified);
+        assert_eq!(Status::new("1", Some("2")), Status::Mismatch);
+        assert_eq!(Status::new("1", None), Status::Unverified);
+    }
+
+    #[test]
+    fn json_output() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&[result()], Format::Json)).unwrap();
+        assert_eq!(json[0]["day"], 1);
+        assert_eq!(json[0]["part"], 2);
+        assert_eq!(json[0]["answer"], "31");
+        assert_eq!(json[0]["elapsed_ns"], 12000);
+        assert_eq!(json[0]["status"], "verified");
+        assert!(json[0].get("explanation").is_none());
+    }
+
+    #[test]
+    fn explanations() {
+        let mut explained = result();
+        explained.explanation = vec!["3 = 1 + 2".to_string()];
+        let table = render(&[explained.clone()], Format::Table);
+        assert!(table.ends_with("\nDay 1 part 2:\n  3 = 1 + 2\n"));
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&[explained], Format::Json)).unwrap();
+        assert_eq!(json[0]["explanation"][0], "3 = 1 + 2");
+    }
+
+    #[test]
+    fn csv_output() {
+        let mut quoted = result();
+        quoted.answer = "a,\"b\"".to_string();
+        let csv = render(&[result(), quoted], Format::Csv);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "day,title,part,answer,elapsed_ns,input_hash,status"
+        );
+        assert!(lines[1].starts_with("1,Historian Hysteria,2,31,12000,"));
+        assert!(lines[2].contains(",\"a,\"\"b\"\"\","));
+    }
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Edit of a runner file registering a day, `None` when the day is already there
+pub type Register = fn(&str, u8) -> Option<String>;
+
+/// Fills the `cargo generate` placeholders used by `template/` for the crate `dayXX`
+pub fn render(template: &str, day: u8) -> String {
+    let name = format!("day{:02}", day);
+    template
+        .replace("{{project-name | capitalize}}", &format!("Day{:02}", day))
+        .replace(
+            "{{project-name | remove: \"day\" | plus: 0}}",
+            &day.to_string(),
+        )
+        .replace("{{project-name}}", &name)
+        .replace("{{crate_name}}", &name)
+}
+
+/// Copies the rendered template into `crate_dir`
+pub fn create(template_dir: &Path, crate_dir: &Path, day: u8) -> io::Result<()> {
+    for entry in fs::read_dir(template_dir)? {
+        let path = entry?.path();
+        let target = crate_dir.join(path.file_name().expect("entries have names"));
+        if path.is_dir() {
+            create(&path, &target, day)?;
+        } else {
+            fs::create_dir_all(crate_dir)?;
+            fs::write(target, render(&fs::read_to_string(&path)?, day))?;
+        }
+    }
+    Ok(())
+}
+
+/// Inserts `line` among the lines starting with `dayXX` so they stay ordered, `None` when the day is already there
+///
+/// Without any day yet the line goes before the `before` line, or to the end.
+fn insert_day_line(content: &str, day: u8, line: &str, before: Option<&str>) -> Option<String> {
+    let day_of = |line: &str| {
+        line.trim_start()
+            .trim_start_matches('&')
+            .strip_prefix("day")
+            .and_then(|rest| rest.get(..2))
+            .and_then(|number| number.parse::<u8>().ok())
+    };
+    let lines = content.lines().collect::<Vec<_>>();
+    if lines.iter().any(|line| day_of(line) == Some(day)) {
+        return None;
+    }
+    let position = match lines
+        .iter()
+        .rposition(|line| day_of(line).is_some_and(|other| other < day))
+    {
+        Some(previous) => previous + 1,
+        None => match lines.iter().position(|line| day_of(line).is_some()) {
+            Some(first) => first,
+            None => match before {
+                Some(before) => lines.iter().position(|line| *line == before)?,
+                None => lines.len(),
+            },
+        },
+    };
+    let mut lines = lines.into_iter().map(str::to_string).collect::<Vec<_>>();
+    lines.insert(position, line.to_string());
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the day crate to the runner dependencies
+pub fn register_dependency(cargo_toml: &str, day: u8) -> Option<String> {
+    insert_day_line(
+        cargo_toml,
+        day,
+        &format!("day{:02} = {{ path = \"../day{:02}\" }}", day, day),
+        None,
+    )
+}
+
+/// Adds the day to the `DAYS` list of the runner
+pub fn register_day(days_rs: &str, day: u8) -> Option<String> {
+    insert_day_line(
+        days_rs,
+        day,
+        &format!("    &day{:02}::Day{:02},", day, day),
+        Some("];"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_placeholders() {
+        let template =
+            "name = \"{{project-name}}\"\nuse {{crate_name}}::{{project-name | capitalize}};\nconst DAY: u8 = {{project-name | remove: \"day\" | plus: 0}};";
+        assert_eq!(
+            render(template, 7),
+            "name = \"day07\"\nuse day07::Day07;\nconst DAY: u8 = 7;"
+        );
+    }
+
+    #[test]
+    fn register_in_order() {
+        let days = "pub const DAYS: &[&dyn AnySolution] = &[\n    &day01::Day01,\n    &day03::Day03,\n];\n";
+        assert_eq!(
+            register_day(days, 2).unwrap(),
+            "pub const DAYS: &[&dyn AnySolution] = &[\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];\n"
+        );
+        assert_eq!(register_day(days, 3), None);
+        let empty = "pub const DAYS: &[&dyn AnySolution] = &[\n];\n";
+        assert!(register_day(empty, 11)
+            .unwrap()
+            .contains("&[\n    &day11::Day11,\n];"));
+
+        let toml =
+            "[dependencies]\nutils = { path = \"../utils\" }\nday10 = { path = \"../day10\" }\n";
+        assert!(register_dependency(toml, 11)
+            .unwrap()
+            .ends_with("day10 = { path = \"../day10\" }\nday11 = { path = \"../day11\" }\n"));
+    }
+}
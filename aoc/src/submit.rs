@@ -0,0 +1,379 @@
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use utils::Part;
+
+/// Wait after a wrong answer when the response does not say how long
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+/// Outcome of a submission as parsed from the response page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Hint, Duration),
+    RateLimited(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Text of the first `<article>` without markup
+    fn message(page: &str) -> String {
+        let article = page
+            .split_once("<article")
+            .and_then(|(_, rest)| rest.split_once('>'))
+            .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+            .unwrap_or(page);
+        let mut text = String::new();
+        let mut in_tag = false;
+        for c in article.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                c if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn parse(page: &str) -> Verdict {
+        let message = Verdict::message(page);
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            let hint = if message.contains("too high") {
+                Hint::TooHigh
+            } else if message.contains("too low") {
+                Hint::TooLow
+            } else {
+                Hint::None
+            };
+            Verdict::Wrong(hint, parse_wait(&message).unwrap_or(DEFAULT_WAIT))
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait(&message).unwrap_or(DEFAULT_WAIT))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(message)
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong(Hint::TooHigh, _) => "too-high",
+            Verdict::Wrong(Hint::TooLow, _) => "too-low",
+            Verdict::Wrong(Hint::None, _) => "wrong",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+
+    /// How long to wait before the next submission
+    fn wait(&self) -> Duration {
+        match self {
+            Verdict::Wrong(_, wait) | Verdict::RateLimited(wait) => *wait,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(hint, wait) => {
+                write!(f, "wrong")?;
+                match hint {
+                    Hint::TooHigh => write!(f, ", too high")?,
+                    Hint::TooLow => write!(f, ", too low")?,
+                    Hint::None => {}
+                }
+                write!(f, " (next try in {}s)", wait.as_secs())
+            }
+            Verdict::RateLimited(wait) => {
+                write!(f, "submitted too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::AlreadySolved => write!(f, "the part is already solved or not unlocked yet"),
+            Verdict::Unknown(message) => write!(f, "unexpected response: {}", message),
+        }
+    }
+}
+
+/// Reads waits like `one minute`, `5 minutes` or `1m 12s left to wait`
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((_, after)) = message.split_once("You have ") {
+        let (time, _) = after.split_once(" left to wait")?;
+        return time
+            .split_whitespace()
+            .try_fold(Duration::ZERO, |total, part| {
+                let (number, unit) = part.split_at(part.len().checked_sub(1)?);
+                let number = number.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(total + Duration::from_secs(number * 3600)),
+                    "m" => Some(total + Duration::from_secs(number * 60)),
+                    "s" => Some(total + Duration::from_secs(number)),
+                    _ => None,
+                }
+            });
+    }
+    let (_, after) = message.split_once("wait ")?;
+    let mut words = after.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words
+        .next()
+        .filter(|unit| unit.starts_with("minute"))
+        .map(|_| Duration::from_secs(minutes * 60))
+}
+
+/// Submission made earlier, one line `timestamp day part answer verdict wait` of the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: String,
+    pub wait: u64,
+}
+
+impl Submission {
+    pub fn new(timestamp: u64, day: u8, part: Part, answer: &str, verdict: &Verdict) -> Self {
+        Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.code().to_string(),
+            wait: verdict.wait().as_secs(),
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let [timestamp, day, part, answer, verdict, wait] =
+            line.split_whitespace().collect::<Vec<_>>()[..]
+        else {
+            return None;
+        };
+        Some(Submission {
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            part: Part::try_from(part.parse::<u8>().ok()?).ok()?,
+            answer: answer.to_string(),
+            verdict: verdict.to_string(),
+            wait: wait.parse().ok()?,
+        })
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self.verdict.as_str(), "too-high" | "too-low" | "wrong")
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.timestamp, self.day, self.part, self.answer, self.verdict, self.wait
+        )
+    }
+}
+
+/// Local history of submissions, keeps wrong guesses and the wait required by the server
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(content) => content.lines().filter_map(Submission::parse).collect(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => vec![],
+            Err(error) => return Err(error),
+        };
+        Ok(SubmissionLog {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn append(&mut self, submission: Submission) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", submission)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Reason why `answer` must not be submitted at `now`, `None` when it can be
+    ///
+    /// Answers are single words, which also keeps the log lines parseable.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Option<String> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Some(format!("`{}` is not a single word", answer));
+        }
+        if let Some(next) = self
+            .submissions
+            .iter()
+            .map(|submission| submission.timestamp + submission.wait)
+            .max()
+            .filter(|&next| next > now)
+        {
+            return Some(format!("wait {}s before the next submission", next - now));
+        }
+        let guesses = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+        for guess in guesses.filter(|guess| guess.is_wrong()) {
+            if guess.answer == answer {
+                return Some(format!(
+                    "{} was already submitted as {}",
+                    answer, guess.verdict
+                ));
+            }
+            let (Ok(guessed), Ok(answer)) = (guess.answer.parse::<i128>(), answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match guess.verdict.as_str() {
+                "too-high" if answer >= guessed => {
+                    return Some(format!("{} is too high, {} already was", answer, guessed))
+                }
+                "too-low" if answer <= guessed => {
+                    return Some(format!("{} is too low, {} already was", answer, guessed))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too high. Please wait one minute before trying again.")),
+            Verdict::Wrong(Hint::TooHigh, Duration::from_secs(60))
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too low. please wait 5 minutes before trying again.")),
+            Verdict::Wrong(Hint::TooLow, Duration::from_secs(300))
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer. If you're stuck, make sure you're using the full input data.")),
+            Verdict::Wrong(Hint::None, DEFAULT_WAIT)
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 12s left to wait.")),
+            Verdict::RateLimited(Duration::from_secs(72))
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            Verdict::parse("<p>Oops</p>"),
+            Verdict::Unknown("Oops".to_string())
+        );
+    }
+
+    #[test]
+    fn log_prevents_resubmission() {
+        let mut log = SubmissionLog {
+            path: PathBuf::new(),
+            submissions: vec![],
+        };
+        let wrong = |hint| Verdict::Wrong(hint, Duration::from_secs(60));
+        log.submissions.push(Submission::new(
+            1000,
+            6,
+            Part::Second,
+            "2100",
+            &wrong(Hint::TooHigh),
+        ));
+        log.submissions.push(Submission::new(
+            1100,
+            6,
+            Part::Second,
+            "1900",
+            &wrong(Hint::TooLow),
+        ));
+        log.submissions.push(Submission::new(
+            1200,
+            6,
+            Part::First,
+            "abc",
+            &wrong(Hint::None),
+        ));
+
+        assert!(log
+            .check(6, Part::Second, "2000", 1150)
+            .unwrap()
+            .contains("wait 110s"));
+        assert_eq!(log.check(6, Part::Second, "2000", 1300), None);
+        assert!(log.check(6, Part::Second, "2100", 1300).is_some());
+        assert!(log
+            .check(6, Part::Second, "2200", 1300)
+            .unwrap()
+            .contains("too high"));
+        assert!(log
+            .check(6, Part::Second, "1800", 1300)
+            .unwrap()
+            .contains("too low"));
+        assert!(log.check(6, Part::First, "abc", 1300).is_some());
+        assert_eq!(log.check(7, Part::Second, "2100", 1300), None);
+        assert!(log.check(7, Part::Second, "21 00", 1300).is_some());
+        assert!(log.check(7, Part::Second, "", 1300).is_some());
+    }
+
+    #[test]
+    fn submission_round_trip() {
+        let submission = Submission::new(
+            1733000000,
+            7,
+            Part::First,
+            "3749",
+            &Verdict::RateLimited(Duration::from_secs(30)),
+        );
+        assert_eq!(Submission::parse(&submission.to_string()), Some(submission));
+    }
+}
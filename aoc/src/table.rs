@@ -0,0 +1,59 @@
+use std::fmt::{self, Display};
+
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Table {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.rows.iter().fold(
+            self.header.iter().map(|h| h.len()).collect(),
+            |mut widths: Vec<usize>, row| {
+                row.iter()
+                    .zip(widths.iter_mut())
+                    .for_each(|(cell, width)| *width = (*width).max(cell.chars().count()));
+                widths
+            },
+        )
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!(" {:<width$} ", cell))
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join("|").trim_end())
+        };
+        write_row(f, &self.header)?;
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", separator.join("+"))?;
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use utils::parser::parse_within;
+use utils::{ParseError, Solution};
+
+fn parse_number(input: &str, line: &str, number: Option<&str>) -> Result<usize, ParseError> {
+    let number =
+        number.ok_or_else(|| ParseError::new(line, "expected two numbers").locate(input, line))?;
+    parse_within(input, number)
+}
+
+fn get_lists(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let mut nums = line.split_ascii_whitespace();
+            Ok((
+                parse_number(input, line, nums.next())?,
+                parse_number(input, line, nums.next())?,
+            ))
+        })
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_lists(input)
+    }
+
+    fn first_part((left, right): &Self::Input) -> usize {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        left.sort();
+        right.sort();
+        left.iter()
+            .zip(right)
+            .map(|(a, b)| a.abs_diff(b))
+            .sum::<usize>()
+    }
+
+    fn second_part((left, right): &Self::Input) -> usize {
+        let right_counts = right.iter().fold(HashMap::new(), |mut data, n| {
+            data.entry(n).or_default();
+            *data.get_mut(n).unwrap() += 1;
+            data
+        });
+        left.iter()
+            .map(|n| n * right_counts.get(n).unwrap_or(&0))
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day01, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day01, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day01, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day01, "input", Part::Second);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day01::parse("1   2\n3   x\n4").unwrap_err();
+        assert_eq!(error.text(), "x");
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 5)));
+        let error = Day01::parse("1   2\n3").unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 1)));
+    }
+}
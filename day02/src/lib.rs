@@ -0,0 +1,130 @@
+use utils::parser::parse_within;
+use utils::{ParseError, Solution};
+
+#[derive(Clone, Copy)]
+enum FlowDirection {
+    Unknown,
+    Increasing,
+    Decreasing,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SafeStatus {
+    Safe,
+    Unsafe,
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|number| parse_within(input, number))
+                .collect()
+        })
+        .collect()
+}
+
+fn check_line(data: &[u32]) -> SafeStatus {
+    data.iter()
+        .fold(
+            (None, FlowDirection::Unknown, SafeStatus::Safe),
+            |(last, flow, status), &next_number| match (last, flow, status) {
+                (_, _, SafeStatus::Unsafe) => (last, flow, status),
+                (None, _, _) => (Some(next_number), FlowDirection::Unknown, SafeStatus::Safe),
+                (Some(last), _, _) if last == next_number || last.abs_diff(next_number) > 3 => {
+                    (Some(next_number), flow, SafeStatus::Unsafe)
+                }
+                (Some(last), FlowDirection::Unknown, _) => (
+                    Some(next_number),
+                    if last < next_number {
+                        FlowDirection::Increasing
+                    } else {
+                        FlowDirection::Decreasing
+                    },
+                    SafeStatus::Safe,
+                ),
+                (Some(last), FlowDirection::Increasing, _) if last < next_number => {
+                    (Some(next_number), flow, SafeStatus::Safe)
+                }
+                (Some(last), FlowDirection::Decreasing, _) if last > next_number => {
+                    (Some(next_number), flow, SafeStatus::Safe)
+                }
+                _ => (Some(next_number), flow, SafeStatus::Unsafe),
+            },
+        )
+        .2
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<u32>>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn first_part(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .map(|line| check_line(line))
+            .filter(|&s| s == SafeStatus::Safe)
+            .count()
+    }
+
+    fn second_part(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter_map(|line| {
+                if let SafeStatus::Safe = check_line(line) {
+                    return Some(());
+                }
+                for i in 0..line.len() {
+                    let mut line_without_one_record = line.clone();
+                    line_without_one_record.remove(i);
+                    if let SafeStatus::Safe = check_line(&line_without_one_record) {
+                        return Some(());
+                    }
+                }
+                None
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day02, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day02, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day02, "test", Part::Second);
+    }
+
+    #[test]
+    fn test_second_part_custom() {
+        utils::check_answer!(Day02, "test_custom", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day02, "input", Part::Second);
+    }
+}
@@ -0,0 +1,87 @@
+use regex::Regex;
+use utils::parser::parse_within;
+use utils::{ParseError, Solution};
+
+pub enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let pattern = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|(do\(\)|don't\(\))").unwrap();
+    pattern
+        .captures_iter(input)
+        .map(|caps| match caps.get(3).map(|m| m.as_str()) {
+            Some("do()") => Ok(Instruction::Do),
+            Some(_) => Ok(Instruction::Dont),
+            None => Ok(Instruction::Mul(
+                parse_within(input, &caps[1])?,
+                parse_within(input, &caps[2])?,
+            )),
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn first_part(instructions: &Self::Input) -> usize {
+        instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(num1, num2) => num1 * num2,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn second_part(instructions: &Self::Input) -> usize {
+        instructions
+            .iter()
+            .fold((0, false), |(sum, skip), instruction| match instruction {
+                Instruction::Do => (sum, false),
+                Instruction::Dont => (sum, true),
+                Instruction::Mul(_, _) if skip => (sum, skip),
+                Instruction::Mul(num1, num2) => (sum + num1 * num2, skip),
+            })
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day03, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day03, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day03, "test2", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day03, "input", Part::Second);
+    }
+}
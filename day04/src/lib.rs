@@ -0,0 +1,95 @@
+use utils::{Coord, Direction8, Grid, ParseError, Solution};
+
+const XMAS: &str = "XMAS";
+const MAS: &str = "MAS";
+const SAM: &str = "SAM";
+
+fn is_pattern_present(
+    grid: &Grid<char>,
+    start: Coord,
+    direction: Direction8,
+    pattern: &str,
+) -> Option<()> {
+    let (dx, dy) = direction.offset();
+    for i in 0..pattern.len() {
+        if *grid.get((start + (dx * i as isize, dy * i as isize))?)? != pattern.chars().nth(i)? {
+            return None;
+        }
+    }
+    Some(())
+}
+
+fn is_x_mas(grid: &Grid<char>, start: Coord) -> Option<()> {
+    let top_left = (start + Direction8::NorthWest)?;
+    let top_right = (start + Direction8::NorthEast)?;
+    is_pattern_present(grid, top_left, Direction8::SouthEast, MAS).or(is_pattern_present(
+        grid,
+        top_left,
+        Direction8::SouthEast,
+        SAM,
+    ))?;
+    is_pattern_present(grid, top_right, Direction8::SouthWest, MAS).or(is_pattern_present(
+        grid,
+        top_right,
+        Direction8::SouthWest,
+        SAM,
+    ))?;
+    Some(())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::from_chars(input, |c| c)
+    }
+
+    fn first_part(grid: &Self::Input) -> usize {
+        grid.find_all(|&c| c == 'X')
+            .flat_map(|start| {
+                Direction8::ALL
+                    .into_iter()
+                    .filter_map(move |dir| is_pattern_present(grid, start, dir, XMAS))
+            })
+            .count()
+    }
+
+    fn second_part(grid: &Self::Input) -> usize {
+        grid.find_all(|&c| c == 'A')
+            .filter_map(|start| is_x_mas(grid, start))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day04, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day04, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day04, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day04, "input", Part::Second);
+    }
+}
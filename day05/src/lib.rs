@@ -0,0 +1,124 @@
+use utils::graph::{CycleError, PartialOrder};
+use utils::parser::parse_within;
+use utils::{ParseError, Solution};
+
+pub type Rules = PartialOrder<usize>;
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<usize>>), ParseError> {
+    let result = input.lines().try_fold(
+        (Rules::new(), vec![], true),
+        |(mut rules, mut lines, parsing_rules), line| {
+            if line.is_empty() {
+                return Ok((rules, lines, false));
+            }
+            if parsing_rules {
+                let (a, b) = line.split_once('|').ok_or_else(|| {
+                    ParseError::new(line, "rule has to be in format `a|b`").locate(input, line)
+                })?;
+                rules.add(parse_within(input, a)?, parse_within(input, b)?);
+            } else {
+                lines.push(
+                    line.split(',')
+                        .map(|a| parse_within(input, a))
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+            Ok((rules, lines, parsing_rules))
+        },
+    )?;
+    Ok((result.0, result.1))
+}
+
+fn get_middle(line: &[usize]) -> usize {
+    let lenght = line.len();
+    *line.get(lenght.wrapping_div(2)).unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (Rules, Vec<Vec<usize>>);
+    type FirstAnswer = usize;
+    type SecondAnswer = Result<usize, CycleError<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part((rules, data): &Self::Input) -> usize {
+        data.iter()
+            .filter(|&line| rules.is_sorted(line))
+            .map(|line| get_middle(line))
+            .sum()
+    }
+
+    fn second_part((rules, data): &Self::Input) -> Result<usize, CycleError<usize>> {
+        data.iter()
+            .filter(|&line| !rules.is_sorted(line))
+            .map(|line| {
+                let rank = rules.rank(line)?;
+                let mut line = line.clone();
+                line.sort_by(|a, b| rank.compare(a, b));
+                Ok(get_middle(&line))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day05, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day05, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day05, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day05, "input", Part::Second);
+    }
+
+    #[test]
+    fn pages_ordered_through_rules() {
+        let (rules, data) = parse_input("1|2\n2|3\n\n3,1,2\n3,9,1,2\n1,9,3").unwrap();
+        assert!(!rules.is_sorted(&data[0]));
+        assert!(!rules.is_sorted(&data[1]));
+        assert_eq!(Day05::first_part(&(rules.clone(), data.clone())), 9);
+        assert_eq!(rules.sort_subset(&data[1]).unwrap(), [9, 1, 2, 3]);
+        assert_eq!(Day05::second_part(&(rules, data)), Ok(2 + 2));
+    }
+
+    #[test]
+    fn rank_sorts_like_sort_subset() {
+        let (rules, data) =
+            parse_input("1|2\n2|3\n4|1\n5|3\n\n3,1,2\n3,9,1,2\n2,5,4,1,3\n7,3,4").unwrap();
+        for line in data {
+            let rank = rules.rank(&line).unwrap();
+            let mut sorted = line.clone();
+            sorted.sort_by(|a, b| rank.compare(a, b));
+            assert!(rules.is_sorted(&sorted));
+            assert_eq!(sorted, rules.sort_subset(&line).unwrap());
+        }
+    }
+
+    #[test]
+    fn cyclic_rules() {
+        let input = parse_input("1|2\n2|1\n\n2,1,5").unwrap();
+        assert_eq!(Day05::second_part(&input).unwrap_err().nodes.len(), 2);
+    }
+}
@@ -0,0 +1,93 @@
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use clap::Parser;
+use day06::render::{Patrol, Style};
+use day06::Day06;
+use utils::input::InputSource;
+use utils::Solution;
+
+/// Draws the guard patrol with `|`, `-` and `+`, obstacles that make the guard loop are `O`
+#[derive(Parser)]
+struct Args {
+    /// Input file, `-` for stdin, defaults to the personal input
+    input: Option<String>,
+    /// Animates the patrol in the terminal, one frame per step without the loop obstacles
+    #[arg(short, long)]
+    animate: bool,
+    /// Delay between animation frames in milliseconds
+    #[arg(short, long, default_value_t = 20)]
+    delay: u64,
+    /// Writes every frame to `stepNNNNN.txt` in this directory instead
+    #[arg(short, long, conflicts_with = "animate")]
+    frames: Option<PathBuf>,
+    /// Disables the colours used on a terminal
+    #[arg(long)]
+    no_color: bool,
+}
+
+fn write_frames(patrol: &Patrol, dir: &Path) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut count = 0;
+    for (index, frame) in patrol.frames(Style::Plain).enumerate() {
+        fs::write(dir.join(format!("step{:05}.txt", index)), frame)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn animate(patrol: &Patrol, style: Style, delay: Duration) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    // clear once then redraw over the previous frame
+    write!(stdout, "\x1b[2J")?;
+    for frame in patrol.frames(style) {
+        write!(stdout, "\x1b[H{}", frame)?;
+        stdout.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let source = InputSource::resolve(
+        Day06::DAY,
+        args.input.as_deref(),
+        Path::new(env!("CARGO_MANIFEST_DIR")),
+    );
+    let input = match source
+        .read()
+        .map_err(|error| error.to_string())
+        .and_then(|input| Day06::parse(&input).map_err(|error| format!("Invalid input: {}", error)))
+    {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (grid, start, direction) = &input;
+    let patrol = Patrol::new(grid, *start, *direction);
+    let style = if args.no_color || !io::stdout().is_terminal() {
+        Style::Plain
+    } else {
+        Style::Ansi
+    };
+    let result = match &args.frames {
+        Some(dir) => write_frames(&patrol, dir)
+            .map(|count| println!("Wrote {} frames to {}", count, dir.display())),
+        None if args.animate => animate(&patrol, style, Duration::from_millis(args.delay)),
+        None => io::stdout().write_all(patrol.picture(style).as_bytes()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
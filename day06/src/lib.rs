@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+use rayon::prelude::*;
+use utils::{Coord, Direction, Grid, ParseError, Solution, Turn};
+
+pub mod render;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Cell {
+    Obstacle,
+    Empty,
+}
+
+const GUARD_TURN: Turn = Turn::Right;
+
+fn parse_input(input: &str) -> Result<(Grid<Cell>, Coord, Direction), ParseError> {
+    let mut guard = None;
+    let grid = Grid::try_from_chars(input, |coord, c| {
+        if let Ok(direction) = Direction::try_from(c) {
+            guard = Some((coord, direction));
+        }
+        Ok(if c == '#' {
+            Cell::Obstacle
+        } else {
+            Cell::Empty
+        })
+    })?;
+    match guard {
+        Some((start, direction)) => Ok((grid, start, direction)),
+        None => Err(ParseError::new("", "no guard (`^`, `>`, `v` or `<`) found")),
+    }
+}
+
+/// Next guard state, it turns in place in front of an obstacle, `None` once it leaves the grid
+fn step(
+    grid: &Grid<Cell>,
+    extra_obstacle: Option<Coord>,
+    guard_coord: Coord,
+    guard_direction: Direction,
+) -> Option<(Coord, Direction)> {
+    let new_coord = (guard_coord + guard_direction)?;
+    match grid.get(new_coord)? {
+        Cell::Obstacle => Some((guard_coord, guard_direction + GUARD_TURN)),
+        Cell::Empty if extra_obstacle == Some(new_coord) => {
+            Some((guard_coord, guard_direction + GUARD_TURN))
+        }
+        Cell::Empty => Some((new_coord, guard_direction)),
+    }
+}
+
+/// Walks the guard one step at a time until it leaves the grid or loops, `extra_obstacle` is laid over the grid
+///
+/// Slower than [`guard_loops`] but follows every cell of the path, kept for visualisation and cross-checks.
+fn process_guard(
+    grid: &Grid<Cell>,
+    extra_obstacle: Option<Coord>,
+    mut guard_coord: Coord,
+    mut guard_direction: Direction,
+) -> (usize, bool) {
+    let mut visited = HashMap::new();
+    let mut loop_detected = false;
+    loop {
+        if is_coord_visited(&mut visited, guard_coord, guard_direction) {
+            loop_detected = true;
+            break;
+        }
+        visited.get_mut(&guard_coord).unwrap().push(guard_direction);
+        match step(grid, extra_obstacle, guard_coord, guard_direction) {
+            Some((new_coord, new_direction)) => {
+                guard_coord = new_coord;
+                guard_direction = new_direction;
+            }
+            None => break,
+        }
+    }
+    (visited.keys().count(), loop_detected)
+}
+
+fn is_coord_visited(
+    visited: &mut HashMap<Coord, Vec<Direction>>,
+    guard_coord: Coord,
+    guard_direction: Direction,
+) -> bool {
+    visited.entry(guard_coord).or_default();
+    visited
+        .get(&guard_coord)
+        .unwrap()
+        .contains(&guard_direction)
+}
+
+/// Steps the guard can walk from every cell in every direction before an obstacle, `None` when it leaves the grid
+struct JumpTable {
+    steps: Grid<[Option<usize>; 4]>,
+}
+
+impl JumpTable {
+    fn new(grid: &Grid<Cell>) -> Self {
+        let mut steps = Grid::filled(grid.width(), grid.height(), [None; 4]);
+        for direction in Direction::ALL {
+            let mut coords = grid.coords().collect::<Vec<_>>();
+            // the next cell in `direction` has to be computed first
+            if matches!(direction, Direction::Right | Direction::Down) {
+                coords.reverse();
+            }
+            for coord in coords {
+                steps[coord][direction as usize] = match coord + direction {
+                    Some(next) => match grid.get(next) {
+                        Some(Cell::Obstacle) => Some(0),
+                        Some(Cell::Empty) => steps[next][direction as usize].map(|steps| steps + 1),
+                        None => None,
+                    },
+                    None => None,
+                };
+            }
+        }
+        JumpTable { steps }
+    }
+
+    /// Cell where the guard turns next, `None` when it leaves the grid
+    fn next_turn(
+        &self,
+        coord: Coord,
+        direction: Direction,
+        extra_obstacle: Option<Coord>,
+    ) -> Option<Coord> {
+        let before_extra = extra_obstacle
+            .and_then(|obstacle| distance(coord, obstacle, direction))
+            .map(|distance| distance - 1);
+        let steps = match (self.steps[coord][direction as usize], before_extra) {
+            (Some(steps), Some(before_extra)) => steps.min(before_extra),
+            (steps, before_extra) => steps.or(before_extra)?,
+        };
+        let (x, y) = direction.offset();
+        coord + (x * steps as isize, y * steps as isize)
+    }
+}
+
+/// Steps from `from` to `to` when `to` lies ahead in `direction`
+fn distance(from: Coord, to: Coord, direction: Direction) -> Option<usize> {
+    match direction {
+        Direction::Up if to.x == from.x && to.y < from.y => Some(from.y - to.y),
+        Direction::Down if to.x == from.x && to.y > from.y => Some(to.y - from.y),
+        Direction::Left if to.y == from.y && to.x < from.x => Some(from.x - to.x),
+        Direction::Right if to.y == from.y && to.x > from.x => Some(to.x - from.x),
+        _ => None,
+    }
+}
+
+/// Guard states as one bit per cell and direction
+struct VisitedStates {
+    bits: Vec<u64>,
+    width: usize,
+}
+
+impl VisitedStates {
+    fn new(width: usize, height: usize) -> Self {
+        VisitedStates {
+            bits: vec![0; (width * height * 4).div_ceil(64)],
+            width,
+        }
+    }
+
+    /// Marks the state, `false` when it was already visited
+    fn insert(&mut self, coord: Coord, direction: Direction) -> bool {
+        let index = (coord.y * self.width + coord.x) * 4 + direction as usize;
+        let mask = 1 << (index % 64);
+        let is_new = self.bits[index / 64] & mask == 0;
+        self.bits[index / 64] |= mask;
+        is_new
+    }
+}
+
+/// Whether the guard walks in a loop, jumping from one turn to the next
+fn guard_loops(
+    table: &JumpTable,
+    extra_obstacle: Option<Coord>,
+    mut guard_coord: Coord,
+    mut guard_direction: Direction,
+) -> bool {
+    let mut visited = VisitedStates::new(table.steps.width(), table.steps.height());
+    while let Some(turn) = table.next_turn(guard_coord, guard_direction, extra_obstacle) {
+        if !visited.insert(turn, guard_direction) {
+            return true;
+        }
+        guard_coord = turn;
+        guard_direction = guard_direction + GUARD_TURN;
+    }
+    false
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (Grid<Cell>, Coord, Direction);
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part((grid, guard_coord, guard_direction): &Self::Input) -> usize {
+        process_guard(grid, None, *guard_coord, *guard_direction).0
+    }
+
+    fn second_part((grid, guard_start_coord, guard_start_direction): &Self::Input) -> usize {
+        loop_obstacles(grid, *guard_start_coord, *guard_start_direction).len()
+    }
+}
+
+/// Positions where a new obstacle makes the guard walk in a loop, in the order the guard reaches them
+pub fn loop_obstacles(
+    grid: &Grid<Cell>,
+    guard_start_coord: Coord,
+    guard_start_direction: Direction,
+) -> Vec<Coord> {
+    let table = JumpTable::new(grid);
+    candidate_obstacles(grid, guard_start_coord, guard_start_direction)
+        .into_par_iter()
+        .filter(|&(obstacle, guard_coord, guard_direction)| {
+            guard_loops(&table, Some(obstacle), guard_coord, guard_direction)
+        })
+        .map(|(obstacle, _, _)| obstacle)
+        .collect()
+}
+
+/// Cells of the guard path with the guard state right before entering them for the first time
+///
+/// An obstacle there leaves the path up to that state unchanged, so its loop check can start from it.
+fn candidate_obstacles(
+    grid: &Grid<Cell>,
+    mut guard_coord: Coord,
+    mut guard_direction: Direction,
+) -> Vec<(Coord, Coord, Direction)> {
+    let mut candidates = vec![];
+    let mut visited = HashMap::new();
+    loop {
+        if is_coord_visited(&mut visited, guard_coord, guard_direction) {
+            break;
+        }
+        visited.get_mut(&guard_coord).unwrap().push(guard_direction);
+        let Some((new_coord, new_direction)) = step(grid, None, guard_coord, guard_direction)
+        else {
+            break;
+        };
+        if !visited.contains_key(&new_coord) {
+            candidates.push((new_coord, guard_coord, guard_direction));
+        }
+        guard_coord = new_coord;
+        guard_direction = new_direction;
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day06, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day06, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day06, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day06, "input", Part::Second);
+    }
+
+    #[test]
+    fn overlay_matches_cloned_grid() {
+        let input =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap();
+        let (grid, start, direction) = parse_input(&input).unwrap();
+        for (obstacle, guard_coord, guard_direction) in candidate_obstacles(&grid, start, direction)
+        {
+            let mut cloned = grid.clone();
+            cloned[obstacle] = Cell::Obstacle;
+            assert_eq!(
+                process_guard(&grid, Some(obstacle), guard_coord, guard_direction).1,
+                process_guard(&cloned, None, start, direction).1
+            );
+        }
+    }
+
+    #[test]
+    fn jumps_match_steps() {
+        let input =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap();
+        let (grid, start, direction) = parse_input(&input).unwrap();
+        let table = JumpTable::new(&grid);
+        assert_eq!(
+            table.next_turn(start, direction, None),
+            Some(Coord { x: 4, y: 1 })
+        );
+        assert_eq!(table.next_turn(start, Direction::Down, None), None);
+        for obstacle in grid.coords().filter(|&coord| coord != start) {
+            assert_eq!(
+                guard_loops(&table, Some(obstacle), start, direction),
+                process_guard(&grid, Some(obstacle), start, direction).1,
+                "obstacle at {:?}",
+                obstacle
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+
+use utils::{Coord, Direction, Grid};
+
+use crate::{loop_obstacles, step, Cell};
+
+const RESET: &str = "\x1b[0m";
+const OBSTACLE: &str = "\x1b[2m";
+const TRAIL: &str = "\x1b[36m";
+const LOOP_OBSTACLE: &str = "\x1b[1;31m";
+const GUARD: &str = "\x1b[1;33m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    /// Colours for the terminal
+    Ansi,
+}
+
+/// Axes the guard walked along in a cell
+#[derive(Debug, Clone, Copy, Default)]
+struct Trail {
+    vertical: bool,
+    horizontal: bool,
+}
+
+impl Trail {
+    fn mark(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up | Direction::Down => self.vertical = true,
+            Direction::Left | Direction::Right => self.horizontal = true,
+        }
+    }
+
+    fn symbol(self) -> Option<char> {
+        match (self.vertical, self.horizontal) {
+            (true, true) => Some('+'),
+            (true, false) => Some('|'),
+            (false, true) => Some('-'),
+            (false, false) => None,
+        }
+    }
+}
+
+fn guard_symbol(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+/// Patrol of the guard until it leaves the grid
+pub struct Patrol<'a> {
+    grid: &'a Grid<Cell>,
+    states: Vec<(Coord, Direction)>,
+}
+
+/// Marks the trail between two consecutive states, `to` is `None` once the guard walks off the grid
+fn mark(trail: &mut Grid<Trail>, from: (Coord, Direction), to: Option<(Coord, Direction)>) {
+    match (from, to) {
+        // turning in place
+        ((from, from_direction), Some((to, to_direction))) if from == to => {
+            trail[from].mark(from_direction);
+            trail[to].mark(to_direction);
+        }
+        ((from, direction), Some((to, _))) => {
+            trail[from].mark(direction);
+            trail[to].mark(direction);
+        }
+        ((from, direction), None) => trail[from].mark(direction),
+    }
+}
+
+impl<'a> Patrol<'a> {
+    pub fn new(grid: &'a Grid<Cell>, guard_coord: Coord, guard_direction: Direction) -> Self {
+        let mut states = vec![(guard_coord, guard_direction)];
+        let mut seen = HashSet::from([(guard_coord, guard_direction)]);
+        while let Some(next) = states
+            .last()
+            .and_then(|&(coord, direction)| step(grid, None, coord, direction))
+            .filter(|&next| seen.insert(next))
+        {
+            states.push(next);
+        }
+        Patrol { grid, states }
+    }
+
+    fn empty_trail(&self) -> Grid<Trail> {
+        Grid::filled(self.grid.width(), self.grid.height(), Trail::default())
+    }
+
+    /// One picture per guard state and a last one once the guard is gone, without the loop
+    /// obstacles
+    pub fn frames(&self, style: Style) -> impl Iterator<Item = String> + '_ {
+        let mut trail = self.empty_trail();
+        let no_obstacles = HashSet::new();
+        (0..=self.states.len()).map(move |index| {
+            let guard = self.states.get(index).copied();
+            if let Some(previous) = index.checked_sub(1) {
+                mark(&mut trail, self.states[previous], guard);
+            }
+            self.draw(&trail, guard, &no_obstacles, style)
+        })
+    }
+
+    /// Whole path with the loop obstacles found by the second part, like the pictures of the
+    /// puzzle description
+    pub fn picture(&self, style: Style) -> String {
+        let mut trail = self.empty_trail();
+        for (index, &from) in self.states.iter().enumerate() {
+            mark(&mut trail, from, self.states.get(index + 1).copied());
+        }
+        let (guard_coord, guard_direction) = self.states[0];
+        let obstacles = loop_obstacles(self.grid, guard_coord, guard_direction)
+            .into_iter()
+            .collect();
+        self.draw(&trail, None, &obstacles, style)
+    }
+
+    fn draw(
+        &self,
+        trail: &Grid<Trail>,
+        guard: Option<(Coord, Direction)>,
+        loop_obstacles: &HashSet<Coord>,
+        style: Style,
+    ) -> String {
+        let mut picture = String::new();
+        for coord in self.grid.coords() {
+            if coord.x == 0 && coord.y > 0 {
+                picture.push('\n');
+            }
+            let (symbol, colour) = match (guard, trail[coord].symbol()) {
+                (Some((guard_coord, direction)), _) if guard_coord == coord => {
+                    (guard_symbol(direction), GUARD)
+                }
+                (_, Some(_)) if loop_obstacles.contains(&coord) => ('O', LOOP_OBSTACLE),
+                (_, Some(symbol)) => (symbol, TRAIL),
+                (_, None) => match self.grid[coord] {
+                    Cell::Obstacle => ('#', OBSTACLE),
+                    Cell::Empty => ('.', ""),
+                },
+            };
+            match style {
+                Style::Ansi if !colour.is_empty() => {
+                    picture += colour;
+                    picture.push(symbol);
+                    picture += RESET;
+                }
+                _ => picture.push(symbol),
+            }
+        }
+        picture.push('\n');
+        picture
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use utils::Solution;
+
+    #[test]
+    fn patrol_picture() {
+        let input =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap();
+        let (grid, start, direction) = Day06::parse(&input).unwrap();
+        let patrol = Patrol::new(&grid, start, direction);
+        assert_eq!(
+            patrol.picture(Style::Plain),
+            "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O+-+-+.
+.+----OO#.
+#O-O--+|..
+......#O..
+"
+        );
+        let frames = patrol.frames(Style::Plain).collect::<Vec<_>>();
+        assert_eq!(frames.len(), patrol.states.len() + 1);
+        // the same path, the loop obstacles are only drawn on the picture
+        let last = frames.last().unwrap();
+        assert!(!last.contains('O'));
+        assert!(last
+            .chars()
+            .zip(patrol.picture(Style::Plain).chars())
+            .all(|(frame, picture)| frame == picture || picture == 'O'));
+        assert!(frames[0].contains(".#..^....."));
+        assert!(frames[1].contains(".#..|.....\n"));
+        assert!(patrol.frames(Style::Ansi).nth(1).unwrap().contains(GUARD));
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::LazyLock;
+
+use clap::builder::PossibleValue;
+use clap::{Parser, ValueEnum};
+use day07::{operator, Day07, Evaluation, Operator, Precedence, Solver, Value};
+use utils::input::InputSource;
+use utils::Solution;
+
+/// Total calibration result with any set of operators
+#[derive(Parser)]
+struct Args {
+    /// Input file, `-` for stdin, defaults to the personal input
+    input: Option<String>,
+    /// Operators between the numbers, `^` is xor and `||N` concatenates in base N (2 to 36)
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "+,*,||"
+    )]
+    operators: Vec<Symbol>,
+    /// Defaults to reverse when every operator can be undone
+    #[arg(short, long, value_enum, conflicts_with = "precedence")]
+    solver: Option<Solver>,
+    /// Apply operators binding tighter first instead of left to right: `**` (grouped from the
+    /// right), then `*`, then `+` and `-`, then `||` and `^`
+    #[arg(short, long)]
+    precedence: bool,
+    /// Override precedence levels as `symbol=level`, higher binds tighter
+    #[arg(short, long, value_delimiter = ',', requires = "precedence")]
+    level: Vec<String>,
+    /// Print how every equation is solved
+    #[arg(short, long)]
+    explain: bool,
+}
+
+/// Symbol of a built-in operator, the concatenations in other bases are accepted but not listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Symbol(&'static str);
+
+const LISTED: [&str; 6] = ["+", "*", "||", "-", "^", "**"];
+
+impl ValueEnum for Symbol {
+    fn value_variants<'a>() -> &'a [Self] {
+        // clap needs static names, the concatenation symbols are built once
+        static SYMBOLS: LazyLock<Vec<Symbol>> = LazyLock::new(|| {
+            LISTED
+                .into_iter()
+                .chain((2..=36).map(|base| &*format!("||{}", base).leak()))
+                .map(Symbol)
+                .collect()
+        });
+        &SYMBOLS
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(self.0).hide(!LISTED.contains(&self.0)))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let operators = args
+        .operators
+        .iter()
+        .map(|symbol| operator::parse::<Value>(symbol.0).expect("clap only accepts known symbols"))
+        .collect::<Vec<_>>();
+    let operators = operators
+        .iter()
+        .map(|op| op.as_ref())
+        .collect::<Vec<&dyn Operator<Value>>>();
+    let solver = match args.solver {
+        Some(Solver::Reverse) if Solver::suited(&operators) == Solver::Forward => {
+            eprintln!("The reverse solver needs operators that can be undone");
+            return ExitCode::FAILURE;
+        }
+        Some(solver) => solver,
+        None => Solver::suited(&operators),
+    };
+    let evaluation = match args.precedence {
+        true => match Precedence::parse(&args.level) {
+            Ok(precedence) => Evaluation::Precedence(precedence),
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        },
+        false => Evaluation::LeftToRight,
+    };
+    let source = InputSource::resolve(
+        Day07::DAY,
+        args.input.as_deref(),
+        Path::new(env!("CARGO_MANIFEST_DIR")),
+    );
+    let data = match source
+        .read()
+        .map_err(|error| error.to_string())
+        .and_then(|input| Day07::parse(&input).map_err(|error| format!("Invalid input: {}", error)))
+    {
+        Ok(data) => data,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.explain {
+        for line in day07::explain(&data, &operators, &evaluation) {
+            println!("{}", line);
+        }
+    }
+    let total = match &evaluation {
+        Evaluation::LeftToRight => day07::process(&data, &operators, solver),
+        Evaluation::Precedence(precedence) => {
+            day07::process_with_precedence(&data, &operators, precedence)
+        }
+    };
+    match total {
+        Ok(total) => {
+            println!("Total: {}", total);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
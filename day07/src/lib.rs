@@ -0,0 +1,927 @@
+use std::fmt::{self, Display};
+use std::ops::ControlFlow;
+
+use clap::ValueEnum;
+use utils::{ParseError, Part, Solution};
+
+pub use number::Number;
+pub use operator::{Add, Associativity, Concat, Mul, Operator, Pow, Precedence, Sub, Xor};
+
+mod number;
+pub mod operator;
+
+/// Type the puzzle is solved in, `usize` unless the `u128` or `bigint` feature is enabled
+#[cfg(feature = "bigint")]
+pub type Value = num_bigint::BigUint;
+#[cfg(all(feature = "u128", not(feature = "bigint")))]
+pub type Value = u128;
+#[cfg(not(any(feature = "u128", feature = "bigint")))]
+pub type Value = usize;
+
+/// Operators of each part, the solvers take any others just as well
+fn first_operators<N: Number>() -> [&'static dyn Operator<N>; 2] {
+    [&Add, &Mul]
+}
+
+fn second_operators<N: Number>() -> [&'static dyn Operator<N>; 3] {
+    [&Add, &Mul, &Concat::DECIMAL]
+}
+
+fn parse_input<N: Number>(input: &str) -> Result<Vec<(N, Vec<N>)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (sum, numbers) = line.split_once(": ").ok_or_else(|| {
+                ParseError::new(line, "equation has to be in format `sum: numbers`")
+                    .locate(input, line)
+            })?;
+            let sum = N::parse(input, sum)?;
+            Ok((
+                sum,
+                numbers
+                    .split_ascii_whitespace()
+                    .map(|num| N::parse(input, num))
+                    .collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+/// Way of finding out whether operators can be inserted between the numbers of an equation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Solver {
+    /// Every partial result from left to right, up to 3^n of them
+    Forward,
+    /// From the target down, only through operators that can be undone
+    Reverse,
+}
+
+impl Solver {
+    /// Reverse when every operator can be undone, otherwise forward
+    pub fn suited<N: Number>(operators: &[&dyn Operator<N>]) -> Solver {
+        if operators.iter().all(|op| op.is_invertible()) {
+            Solver::Reverse
+        } else {
+            Solver::Forward
+        }
+    }
+
+    fn can_add_operators<N: Number>(
+        self,
+        sum: &N,
+        numbers: &[N],
+        operators: &[&dyn Operator<N>],
+    ) -> bool {
+        match self {
+            Solver::Forward => can_add_operators(sum, numbers, operators),
+            Solver::Reverse => can_remove_operators(sum, numbers, operators),
+        }
+    }
+}
+
+/// Order in which the operators of an equation are applied
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Evaluation {
+    /// Strictly left to right, as in the puzzle
+    #[default]
+    LeftToRight,
+    /// Operators binding tighter first, operators of the same level left to right
+    Precedence(Precedence),
+}
+
+/// Left operands with their operator and its level, waiting for operators binding tighter to be applied
+type Pending<'a, N> = Vec<(N, &'a dyn Operator<N>, u8)>;
+
+/// Applies the pending operators binding at least as tight as the next operator of `level` to
+/// `right`, operators of the same level only when it groups from the left, the result is the
+/// right operand of the next operator
+fn reduce<N: Number>(
+    pending: &mut Pending<N>,
+    mut right: N,
+    level: u8,
+    associativity: Associativity,
+) -> Option<N> {
+    while pending.last().is_some_and(|&(_, _, pending_level)| {
+        pending_level > level || (pending_level == level && associativity == Associativity::Left)
+    }) {
+        let (left, op, _) = pending.pop().expect("checked above");
+        right = op.apply(&left, &right)?;
+    }
+    Some(right)
+}
+
+/// Value of the equation's right side with `assignment[i]` between `numbers[i]` and `numbers[i + 1]`
+pub fn evaluate<N: Number>(
+    numbers: &[N],
+    assignment: &[&dyn Operator<N>],
+    evaluation: &Evaluation,
+) -> Option<N> {
+    let (first, numbers) = numbers.split_first()?;
+    match evaluation {
+        Evaluation::LeftToRight => numbers
+            .iter()
+            .zip(assignment)
+            .try_fold(first.clone(), |res, (num, op)| op.apply(&res, num)),
+        Evaluation::Precedence(precedence) => {
+            let mut pending = vec![];
+            let mut right = first.clone();
+            for (num, &op) in numbers.iter().zip(assignment) {
+                let level = precedence.level(op);
+                let left = reduce(&mut pending, right, level, op.associativity())?;
+                pending.push((left, op, level));
+                right = num.clone();
+            }
+            reduce(&mut pending, right, 0, Associativity::Left)
+        }
+    }
+}
+
+/// Whether no operator can make a result smaller with any of `numbers` as its right operand
+fn never_decrease<N: Number>(operators: &[&dyn Operator<N>], numbers: &[N]) -> bool {
+    numbers
+        .iter()
+        .all(|num| operators.iter().all(|op| op.never_decreases(num)))
+}
+
+/// Branches that overflow `N` cannot reach `sum` and are pruned, so are results above `sum` when no
+/// operator can make them smaller with the numbers left
+fn can_add_operators<N: Number>(sum: &N, numbers: &[N], operators: &[&dyn Operator<N>]) -> bool {
+    let Some((first, numbers)) = numbers.split_first() else {
+        return false;
+    };
+    numbers
+        .iter()
+        .enumerate()
+        .fold(vec![first.clone()], |results, (index, num)| {
+            let prune = never_decrease(operators, &numbers[index + 1..]);
+            results
+                .iter()
+                .flat_map(|res| {
+                    operators
+                        .iter()
+                        .filter_map(|op| {
+                            let op_result = op.apply(res, num)?;
+                            if !prune || op_result <= *sum {
+                                return Some(op_result);
+                            }
+                            None
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        })
+        .contains(sum)
+}
+
+/// Undoes the operator before the last number, for the usual operators only a few of them can be
+/// undone, so most branches end right away
+fn can_remove_operators<N: Number>(sum: &N, numbers: &[N], operators: &[&dyn Operator<N>]) -> bool {
+    assert!(
+        operators.iter().all(|op| op.is_invertible()),
+        "the reverse solver needs invertible operators"
+    );
+    match numbers.split_last() {
+        None => false,
+        Some((first, [])) => first == sum,
+        Some((last, numbers)) => operators.iter().any(|op| {
+            if op.absorbs(sum, last) {
+                return complete_operators(numbers, operators, &mut vec![], &mut |_| {
+                    ControlFlow::Break(())
+                })
+                .is_break();
+            }
+            op.undo(sum, last)
+                .is_some_and(|previous| can_remove_operators(&previous, numbers, operators))
+        }),
+    }
+}
+
+/// Calls `found` with every assignment of `numbers` that evaluates without failing, whatever its
+/// value, until it breaks
+fn complete_operators<'a, N: Number>(
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    added: &mut Assignment<'a, N>,
+    found: &mut impl FnMut(&Assignment<'a, N>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    match numbers {
+        [] => ControlFlow::Continue(()),
+        [_] => found(added),
+        [left, right, numbers @ ..] => {
+            for &op in operators {
+                let Some(result) = op.apply(left, right) else {
+                    continue;
+                };
+                let numbers = [&[result], numbers].concat();
+                added.push(op);
+                let flow = complete_operators(&numbers, operators, added, found);
+                added.pop();
+                flow?;
+            }
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// Operators between numbers, `assignment[i]` is put between `numbers[i]` and `numbers[i + 1]`
+pub type Assignment<'a, N> = Vec<&'a dyn Operator<N>>;
+
+/// Searches assignments like [`can_add_operators`] one by one, `found` gets each of them and decides
+/// whether to go on
+fn add_operators<'a, N: Number>(
+    sum: &N,
+    result: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    added: &mut Assignment<'a, N>,
+    found: &mut impl FnMut(Assignment<'a, N>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let Some((num, numbers)) = numbers.split_first() else {
+        return match result == sum {
+            true => found(added.clone()),
+            false => ControlFlow::Continue(()),
+        };
+    };
+    let prune = never_decrease(operators, numbers);
+    for &op in operators {
+        let Some(next) = op.apply(result, num).filter(|next| !prune || next <= sum) else {
+            continue;
+        };
+        added.push(op);
+        let flow = add_operators(sum, &next, numbers, operators, added, found);
+        added.pop();
+        flow?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Searches assignments one by one evaluating them with `levels[i]` as the precedence level of
+/// `operators[i]`, `found` gets each of them and decides whether to go on
+///
+/// When every operator is monotone and none can make a result smaller with the numbers left, the
+/// operators after the next number only make the pending right operands and the result grow, so
+/// applying the pending operators right away gives a lower bound of every completion, branches
+/// whose bound is above `sum` are pruned.
+#[allow(clippy::too_many_arguments)]
+fn add_operators_by_precedence<'a, N: Number>(
+    sum: &N,
+    pending: &Pending<'a, N>,
+    right: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    levels: &[u8],
+    added: &mut Assignment<'a, N>,
+    found: &mut impl FnMut(Assignment<'a, N>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let Some((num, numbers)) = numbers.split_first() else {
+        return match reduce(&mut pending.clone(), right.clone(), 0, Associativity::Left) {
+            Some(value) if value == *sum => found(added.clone()),
+            _ => ControlFlow::Continue(()),
+        };
+    };
+    let prune = operators.iter().all(|op| op.is_monotone()) && never_decrease(operators, numbers);
+    for (&op, &level) in operators.iter().zip(levels) {
+        let mut next = pending.clone();
+        let Some(left) = reduce(&mut next, right.clone(), level, op.associativity()) else {
+            continue;
+        };
+        next.push((left, op, level));
+        if prune
+            && reduce(&mut next.clone(), num.clone(), 0, Associativity::Left)
+                .is_none_or(|bound| bound > *sum)
+        {
+            continue;
+        }
+        added.push(op);
+        let flow =
+            add_operators_by_precedence(sum, &next, num, numbers, operators, levels, added, found);
+        added.pop();
+        flow?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Searches assignments like [`can_remove_operators`], `found` gets each of them and decides whether to go on
+fn remove_operators<'a, N: Number>(
+    sum: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    removed: &mut Assignment<'a, N>,
+    found: &mut impl FnMut(Assignment<'a, N>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    match numbers.split_last() {
+        None => ControlFlow::Continue(()),
+        Some((first, [])) if first == sum => found(removed.iter().rev().copied().collect()),
+        Some((_, [])) => ControlFlow::Continue(()),
+        Some((last, numbers)) => {
+            for &op in operators {
+                if op.absorbs(sum, last) {
+                    complete_operators(numbers, operators, &mut vec![], &mut |before| {
+                        found(
+                            before
+                                .iter()
+                                .copied()
+                                .chain([op])
+                                .chain(removed.iter().rev().copied())
+                                .collect(),
+                        )
+                    })?;
+                    continue;
+                }
+                let Some(previous) = op.undo(sum, last) else {
+                    continue;
+                };
+                removed.push(op);
+                let flow = remove_operators(&previous, numbers, operators, removed, found);
+                removed.pop();
+                flow?;
+            }
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// Calls `found` with the assignments giving `sum` until it breaks, left to right evaluation is
+/// searched with [`Solver::suited`]
+fn search<'a, N: Number>(
+    sum: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    evaluation: &Evaluation,
+    found: &mut impl FnMut(Assignment<'a, N>) -> ControlFlow<()>,
+) {
+    let Some((first, rest)) = numbers.split_first() else {
+        return;
+    };
+    let _ = match evaluation {
+        Evaluation::Precedence(precedence) => add_operators_by_precedence(
+            sum,
+            &vec![],
+            first,
+            rest,
+            operators,
+            &operators
+                .iter()
+                .map(|&op| precedence.level(op))
+                .collect::<Vec<_>>(),
+            &mut vec![],
+            found,
+        ),
+        Evaluation::LeftToRight => match Solver::suited(operators) {
+            Solver::Forward => add_operators(sum, first, rest, operators, &mut vec![], found),
+            Solver::Reverse => remove_operators(sum, numbers, operators, &mut vec![], found),
+        },
+    };
+}
+
+/// Operators to put between `numbers` to get `sum`, the first assignment found
+pub fn find_assignment<'a, N: Number>(
+    sum: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    evaluation: &Evaluation,
+) -> Option<Assignment<'a, N>> {
+    let mut assignment = None;
+    search(sum, numbers, operators, evaluation, &mut |found| {
+        assignment = Some(found);
+        ControlFlow::Break(())
+    });
+    assignment
+}
+
+/// Every assignment of operators between `numbers` giving `sum`
+pub fn all_assignments<'a, N: Number>(
+    sum: &N,
+    numbers: &[N],
+    operators: &[&'a dyn Operator<N>],
+    evaluation: &Evaluation,
+) -> Vec<Assignment<'a, N>> {
+    let mut assignments = vec![];
+    search(sum, numbers, operators, evaluation, &mut |found| {
+        assignments.push(found);
+        ControlFlow::Continue(())
+    });
+    assignments
+}
+
+pub fn count_assignments<N: Number>(
+    sum: &N,
+    numbers: &[N],
+    operators: &[&dyn Operator<N>],
+    evaluation: &Evaluation,
+) -> usize {
+    let mut count = 0;
+    search(sum, numbers, operators, evaluation, &mut |_| {
+        count += 1;
+        ControlFlow::Continue(())
+    });
+    count
+}
+
+/// Equation with its operators, like `190 = 10 * 19`
+pub fn render<N: Number>(sum: &N, numbers: &[N], assignment: &[&dyn Operator<N>]) -> String {
+    let mut expression = format!("{} =", sum);
+    for (index, number) in numbers.iter().enumerate() {
+        if let Some(op) = index.checked_sub(1).and_then(|index| assignment.get(index)) {
+            expression += &format!(" {}", op.symbol());
+        }
+        expression += &format!(" {}", number);
+    }
+    expression
+}
+
+/// Solved equations with their first assignment and how many there are, and unsolvable ones
+pub fn explain<N: Number>(
+    data: &[(N, Vec<N>)],
+    operators: &[&dyn Operator<N>],
+    evaluation: &Evaluation,
+) -> Vec<String> {
+    data.iter()
+        .map(
+            |(sum, numbers)| match find_assignment(sum, numbers, operators, evaluation) {
+                Some(assignment) => match count_assignments(sum, numbers, operators, evaluation) {
+                    1 => render(sum, numbers, &assignment),
+                    count => format!("{} ({} ways)", render(sum, numbers, &assignment), count),
+                },
+                None => format!(
+                    "{}: {} has no solution",
+                    sum,
+                    numbers
+                        .iter()
+                        .map(|number| number.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ),
+            },
+        )
+        .collect()
+}
+
+/// Total of the true equations does not fit in the number type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TotalOverflow;
+
+impl Display for TotalOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "total overflows, enable the `u128` or `bigint` feature")
+    }
+}
+
+impl std::error::Error for TotalOverflow {}
+
+fn total<N: Number>(
+    data: &[(N, Vec<N>)],
+    solvable: impl Fn(&N, &[N]) -> bool,
+) -> Result<N, TotalOverflow> {
+    data.iter()
+        .filter(|(sum, numbers)| solvable(sum, numbers))
+        .try_fold(N::zero(), |total, (sum, _)| {
+            total.checked_add(sum).ok_or(TotalOverflow)
+        })
+}
+
+/// Total of the equations that `operators` can make true with operator precedence
+pub fn process_with_precedence<N: Number>(
+    data: &[(N, Vec<N>)],
+    operators: &[&dyn Operator<N>],
+    precedence: &Precedence,
+) -> Result<N, TotalOverflow> {
+    let evaluation = Evaluation::Precedence(precedence.clone());
+    total(data, |sum, numbers| {
+        find_assignment(sum, numbers, operators, &evaluation).is_some()
+    })
+}
+
+/// Total of the equations that `operators` can make true evaluated left to right
+pub fn process<N: Number>(
+    data: &[(N, Vec<N>)],
+    operators: &[&dyn Operator<N>],
+    solver: Solver,
+) -> Result<N, TotalOverflow> {
+    total(data, |sum, numbers| {
+        solver.can_add_operators(sum, numbers, operators)
+    })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<(Value, Vec<Value>)>;
+    type FirstAnswer = Result<Value, TotalOverflow>;
+    type SecondAnswer = Result<Value, TotalOverflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part(data: &Self::Input) -> Result<Value, TotalOverflow> {
+        process(data, &first_operators(), Solver::Reverse)
+    }
+
+    fn second_part(data: &Self::Input) -> Result<Value, TotalOverflow> {
+        process(data, &second_operators(), Solver::Reverse)
+    }
+
+    fn explain(data: &Self::Input, part: Part) -> Vec<String> {
+        match part {
+            Part::First => explain(data, &first_operators(), &Evaluation::LeftToRight),
+            Part::Second => explain(data, &second_operators(), &Evaluation::LeftToRight),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(assignment: &[&dyn Operator<u64>]) -> Vec<String> {
+        assignment
+            .iter()
+            .map(|op| op.symbol().into_owned())
+            .collect()
+    }
+
+    /// Pseudo-random numbers below the given range, the same for every run
+    fn random_numbers(mut seed: u64) -> impl FnMut(u64) -> u64 {
+        move |range| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % range
+        }
+    }
+
+    /// Operators of every comma separated list of symbols
+    fn operator_sets<const SETS: usize>(
+        lists: [&str; SETS],
+    ) -> [Vec<Box<dyn Operator<u64>>>; SETS] {
+        lists.map(|list| {
+            list.split(',')
+                .map(|symbol| operator::parse(symbol).unwrap())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day07, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day07, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day07, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day07, "input", Part::Second);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = Day07::parse("190: 10 19\n3267 81 40 27").unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 1)));
+        let error = Day07::parse("190: 10 19\n3267: 81 4O 27").unwrap_err();
+        assert_eq!(error.text(), "4O");
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 10)));
+    }
+
+    #[test]
+    fn operators_go_between_numbers() {
+        let data = parse_input::<u64>("5: 3 5\n35: 3 5").unwrap();
+        for solver in [Solver::Forward, Solver::Reverse] {
+            assert!(!solver.can_add_operators(&data[0].0, &data[0].1, &second_operators()));
+            assert!(solver.can_add_operators(&data[1].0, &data[1].1, &second_operators()));
+        }
+    }
+
+    #[test]
+    fn overflowing_branches_are_pruned() {
+        let input = "18446744073709551615: 4294967296 4294967296 1\n18446744073709551616: 4294967296 4294967296";
+        assert!(parse_input::<u64>(input).is_err());
+        let data = parse_input::<u64>(input.lines().next().unwrap()).unwrap();
+        assert_eq!(process(&data, &first_operators(), Solver::Forward), Ok(0));
+        let data = parse_input::<u128>(input).unwrap();
+        for solver in [Solver::Forward, Solver::Reverse] {
+            assert_eq!(
+                process(&data, &first_operators(), solver),
+                Ok(18446744073709551616)
+            );
+            assert_eq!(
+                process(&data, &second_operators(), solver),
+                Ok(18446744073709551616)
+            );
+        }
+    }
+
+    #[test]
+    fn multiplying_by_zero() {
+        let data = parse_input::<u64>("0: 5 3 0\n7: 5 3 0 7\n2: 2 3 0").unwrap();
+        for solver in [Solver::Forward, Solver::Reverse] {
+            assert_eq!(process(&data, &first_operators(), solver), Ok(7));
+            assert_eq!(process(&data, &second_operators(), solver), Ok(7));
+        }
+        assert_eq!(
+            all_assignments(&0, &data[0].1, &first_operators(), &Evaluation::LeftToRight)
+                .iter()
+                .map(|assignment| symbols(assignment))
+                .collect::<Vec<_>>(),
+            [["+", "*"], ["*", "*"]]
+        );
+        assert_eq!(
+            process_with_precedence(&data, &first_operators(), &Precedence::default()),
+            Ok(7 + 2)
+        );
+    }
+
+    #[test]
+    fn overflowing_total_is_an_error() {
+        let data = parse_input::<u64>("18446744073709551615: 18446744073709551615\n1: 1").unwrap();
+        for solver in [Solver::Forward, Solver::Reverse] {
+            assert_eq!(
+                process(&data, &first_operators(), solver),
+                Err(TotalOverflow)
+            );
+        }
+        assert_eq!(
+            process_with_precedence(&data, &first_operators(), &Precedence::default()),
+            Err(TotalOverflow)
+        );
+        assert_eq!(
+            process(&data[..1], &first_operators(), Solver::Reverse),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn explanations() {
+        let data = parse_input::<u64>(
+            &std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap(),
+        )
+        .unwrap();
+        let lines = explain(&data, &second_operators(), &Evaluation::LeftToRight);
+        assert_eq!(lines[0], "190 = 10 * 19");
+        assert_eq!(lines[1], "3267 = 81 * 40 + 27 (2 ways)");
+        assert_eq!(lines[2], "83: 17 5 has no solution");
+        assert_eq!(lines[3], "156 = 15 || 6");
+        assert_eq!(lines[4], "7290 = 6 * 8 || 6 * 15");
+        let (sum, numbers) = &data[1];
+        assert_eq!(
+            all_assignments(sum, numbers, &first_operators(), &Evaluation::LeftToRight)
+                .iter()
+                .map(|assignment| symbols(assignment))
+                .collect::<Vec<_>>(),
+            [["*", "+"], ["+", "*"]]
+        );
+        assert_eq!(
+            count_assignments(
+                &83u64,
+                &[17, 5],
+                &second_operators(),
+                &Evaluation::LeftToRight
+            ),
+            0
+        );
+        assert_eq!(
+            find_assignment(
+                &190u64,
+                &[10, 19],
+                &first_operators(),
+                &Evaluation::LeftToRight
+            )
+            .map(|found| symbols(&found)),
+            Some(vec!["*".to_string()])
+        );
+    }
+
+    #[test]
+    fn solvers_agree() {
+        let mut data = parse_input::<u64>(
+            &std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap(),
+        )
+        .unwrap();
+        // pseudo-random equations, half of them built to be solvable
+        let mut random = random_numbers(7);
+        for _ in 0..500 {
+            let numbers = (0..2 + random(6)).map(|_| random(30)).collect::<Vec<_>>();
+            let sum = if random(2) == 0 {
+                numbers[1..]
+                    .iter()
+                    .fold(numbers[0], |res, &num| match random(3) {
+                        0 => res + num,
+                        1 => res * num,
+                        _ => res.checked_concat(&num, 10).unwrap(),
+                    })
+            } else {
+                1 + random(100_000)
+            };
+            data.push((sum, numbers));
+        }
+        let operators = operator_sets(["+,*", "+,*,||", "+,-,^", "*,||2,-"]);
+        for operators in &operators {
+            let operators = operators.iter().map(|op| op.as_ref()).collect::<Vec<_>>();
+            let operators = operators.as_slice();
+            for (sum, numbers) in &data {
+                let solvable = Solver::Forward.can_add_operators(sum, numbers, operators);
+                assert_eq!(
+                    solvable,
+                    Solver::Reverse.can_add_operators(sum, numbers, operators),
+                    "{}: {:?}",
+                    sum,
+                    numbers
+                );
+                for assignment in all_assignments(sum, numbers, operators, &Evaluation::LeftToRight)
+                {
+                    assert_eq!(assignment.len(), numbers.len() - 1);
+                    assert_eq!(
+                        evaluate(numbers, &assignment, &Evaluation::LeftToRight),
+                        Some(*sum)
+                    );
+                }
+                assert_eq!(
+                    find_assignment(sum, numbers, operators, &Evaluation::LeftToRight).is_some(),
+                    solvable
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn other_operators() {
+        let data = parse_input::<u64>("81: 3 4\n2: 5 3\n6: 5 3\n7: 1 1 1").unwrap();
+        let operators: [&dyn Operator<u64>; 3] = [&Pow, &Sub, &Xor];
+        assert_eq!(Solver::suited(&operators), Solver::Forward);
+        assert_eq!(Solver::suited(&operators[1..]), Solver::Reverse);
+        assert_eq!(process(&data, &operators, Solver::Forward), Ok(81 + 2 + 6));
+        assert_eq!(process(&data, &operators[1..], Solver::Reverse), Ok(2 + 6));
+        assert_eq!(
+            explain(&data, &operators, &Evaluation::LeftToRight),
+            [
+                "81 = 3 ** 4",
+                "2 = 5 - 3",
+                "6 = 5 ^ 3",
+                "7: 1 1 1 has no solution"
+            ]
+        );
+        let binary: [&dyn Operator<u64>; 1] = [&Concat::new(2).unwrap()];
+        assert_eq!(
+            explain(&data[3..], &binary, &Evaluation::LeftToRight),
+            ["7 = 1 ||2 1 ||2 1"]
+        );
+    }
+
+    #[test]
+    fn precedence_evaluation() {
+        let conventional = Evaluation::Precedence(Precedence::default());
+        let data = parse_input::<u64>(
+            &std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/test.txt"))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            explain(&data[..2], &first_operators(), &conventional),
+            ["190 = 10 * 19", "3267 = 81 * 40 + 27"]
+        );
+        assert_eq!(
+            explain(&data[8..], &first_operators(), &conventional),
+            ["292: 11 6 16 20 has no solution"]
+        );
+        assert_eq!(
+            process_with_precedence(&data, &second_operators(), &Precedence::default()),
+            Ok(190 + 3267 + 156)
+        );
+        let assignment: [&dyn Operator<u64>; 3] = [&Add, &Mul, &Concat::DECIMAL];
+        assert_eq!(
+            evaluate(&[2, 3, 4, 5], &assignment, &conventional),
+            Some(145)
+        );
+        assert_eq!(
+            evaluate(&[2, 3, 4, 5], &assignment, &Evaluation::LeftToRight),
+            Some(205)
+        );
+        let reordered = Evaluation::Precedence(Precedence::default().with("||", 3));
+        assert_eq!(evaluate(&[2, 3, 4, 5], &assignment, &reordered), Some(137));
+        assert_eq!(evaluate(&[7u64], &[], &conventional), Some(7));
+        let powers: [&dyn Operator<u64>; 3] = [&Pow, &Pow, &Mul];
+        assert_eq!(evaluate(&[2, 3, 2], &powers[..2], &conventional), Some(512));
+        assert_eq!(
+            evaluate(&[2, 3, 2], &powers[..2], &Evaluation::LeftToRight),
+            Some(64)
+        );
+        assert_eq!(evaluate(&[2, 3, 2, 2], &powers, &conventional), Some(1024));
+        assert_eq!(
+            explain(&[(512u64, vec![2, 3, 2])], &powers[1..], &conventional),
+            ["512 = 2 ** 3 ** 2"]
+        );
+    }
+
+    /// `a # b = a + 10 - b`, never below `a` but smaller for a larger `b`
+    struct Complement;
+
+    impl Operator<u64> for Complement {
+        fn symbol(&self) -> std::borrow::Cow<'static, str> {
+            "#".into()
+        }
+
+        fn apply(&self, left: &u64, right: &u64) -> Option<u64> {
+            Some(left + 10u64.checked_sub(*right)?)
+        }
+
+        fn never_decreases(&self, right: &u64) -> bool {
+            *right <= 10
+        }
+    }
+
+    #[test]
+    fn precedence_search_needs_monotone_operators() {
+        let conventional = Evaluation::Precedence(Precedence::default());
+        let operators: [&dyn Operator<u64>; 2] = [&Complement, &Mul];
+        assert_eq!(
+            explain(&[(9, vec![5, 2, 3])], &operators, &conventional),
+            ["9 = 5 # 2 * 3"]
+        );
+    }
+
+    #[test]
+    fn precedence_search_matches_enumeration() {
+        let uniform = Precedence::default()
+            .with("+", 1)
+            .with("*", 1)
+            .with("||", 1)
+            .with("^", 1);
+        let evaluations = [
+            Evaluation::Precedence(Precedence::default()),
+            Evaluation::Precedence(Precedence::default().with("||", 5).with("-", 3)),
+        ];
+        let mut random = random_numbers(11);
+        let operators = operator_sets(["+,*,||", "+,*,-", "+,*,**", "+,-,^", "*,||,^"]);
+        for _ in 0..300 {
+            let numbers = (0..2 + random(5)).map(|_| random(20)).collect::<Vec<_>>();
+            for operators in &operators {
+                let operators = operators.iter().map(|op| op.as_ref()).collect::<Vec<_>>();
+                let assignments = (0..numbers.len() - 1).fold(vec![vec![]], |partial, _| {
+                    partial
+                        .iter()
+                        .flat_map(|assignment: &Assignment<u64>| {
+                            operators.iter().map(move |&op| {
+                                let mut assignment = assignment.clone();
+                                assignment.push(op);
+                                assignment
+                            })
+                        })
+                        .collect()
+                });
+                // a reachable target most of the time, otherwise an arbitrary one
+                let sum = match random(4) {
+                    0 => 1 + random(10_000),
+                    _ => {
+                        let index = random(assignments.len() as u64) as usize;
+                        match evaluate(&numbers, &assignments[index], &evaluations[0]) {
+                            Some(sum) => sum,
+                            None => continue,
+                        }
+                    }
+                };
+                for evaluation in &evaluations {
+                    let expected = assignments
+                        .iter()
+                        .filter(|assignment| {
+                            evaluate(&numbers, assignment, evaluation) == Some(sum)
+                        })
+                        .count();
+                    assert_eq!(
+                        count_assignments(&sum, &numbers, &operators, evaluation),
+                        expected,
+                        "{}: {:?}",
+                        sum,
+                        numbers
+                    );
+                }
+                if operators
+                    .iter()
+                    .all(|op| op.associativity() == Associativity::Left)
+                {
+                    assert_eq!(
+                        count_assignments(
+                            &sum,
+                            &numbers,
+                            &operators,
+                            &Evaluation::Precedence(uniform.clone())
+                        ),
+                        count_assignments(&sum, &numbers, &operators, &Evaluation::LeftToRight)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::{Debug, Display};
+
+use utils::parser::parse_within;
+use utils::ParseError;
+
+/// Unsigned integer the equations are evaluated in, operations return `None` on overflow
+pub trait Number: Clone + Ord + Debug + Display + Send + Sync + Sized {
+    fn zero() -> Self;
+    /// Parses `part` of `source`, the error is located within `source`
+    fn parse(source: &str, part: &str) -> Result<Self, ParseError>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// Digits of `self` followed by the digits of `other`, both written in `base`
+    fn checked_concat(&self, other: &Self, base: u32) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    /// Quotient when `other` divides `self` without remainder
+    fn exact_div(&self, other: &Self) -> Option<Self>;
+    /// Inverse of [`Number::checked_concat`], the number whose concatenation with `other` is `self`
+    fn strip_suffix(&self, other: &Self, base: u32) -> Option<Self>;
+    /// `self` raised to `other`
+    fn checked_pow(&self, other: &Self) -> Option<Self>;
+    fn xor(&self, other: &Self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($type:ty),*) => {
+        $(impl Number for $type {
+            fn zero() -> Self {
+                0
+            }
+
+            fn parse(source: &str, part: &str) -> Result<Self, ParseError> {
+                parse_within(source, part)
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_add(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_mul(*self, *other)
+            }
+
+            fn checked_concat(&self, other: &Self, base: u32) -> Option<Self> {
+                (base as $type)
+                    .checked_pow(other.checked_ilog(base as $type).unwrap_or(0) + 1)
+                    .and_then(|shift| <$type>::checked_mul(*self, shift))
+                    .and_then(|shifted| <$type>::checked_add(shifted, *other))
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_sub(*self, *other)
+            }
+
+            fn exact_div(&self, other: &Self) -> Option<Self> {
+                (*other != 0 && self % other == 0).then(|| self / other)
+            }
+
+            fn strip_suffix(&self, other: &Self, base: u32) -> Option<Self> {
+                let rest = <$type>::checked_sub(*self, *other)?;
+                // a shift wider than the type only divides zero
+                match (base as $type).checked_pow(other.checked_ilog(base as $type).unwrap_or(0) + 1) {
+                    Some(shift) => (rest % shift == 0).then(|| rest / shift),
+                    None => (rest == 0).then_some(0),
+                }
+            }
+
+            fn checked_pow(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_pow(*self, u32::try_from(*other).ok()?)
+            }
+
+            fn xor(&self, other: &Self) -> Self {
+                self ^ other
+            }
+        })*
+    };
+}
+
+impl_number!(u64, u128, usize);
+
+#[cfg(feature = "bigint")]
+impl Number for num_bigint::BigUint {
+    fn zero() -> Self {
+        num_bigint::BigUint::ZERO
+    }
+
+    fn parse(source: &str, part: &str) -> Result<Self, ParseError> {
+        part.parse().map_err(|error| {
+            ParseError::new(part, format!("cannot parse BigUint: {}", error)).locate(source, part)
+        })
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_concat(&self, other: &Self, base: u32) -> Option<Self> {
+        let digits = other.to_str_radix(base).len() as u32;
+        Some(self * Self::from(base).pow(digits) + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| self - other)
+    }
+
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        (*other != Self::ZERO && (self % other) == Self::ZERO).then(|| self / other)
+    }
+
+    fn strip_suffix(&self, other: &Self, base: u32) -> Option<Self> {
+        let rest = Number::checked_sub(self, other)?;
+        let shift = Self::from(base).pow(other.to_str_radix(base).len() as u32);
+        (&rest % &shift == Self::ZERO).then(|| rest / shift)
+    }
+
+    fn checked_pow(&self, other: &Self) -> Option<Self> {
+        Some(self.pow(u32::try_from(other).ok()?))
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        self ^ other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_operations() {
+        assert_eq!(12u64.checked_concat(&345, 10), Some(12345));
+        assert_eq!(0u64.checked_concat(&7, 10), Some(7));
+        assert_eq!(5u64.checked_concat(&0, 10), Some(50));
+        assert_eq!(0b101u64.checked_concat(&0b11, 2), Some(0b10111));
+        assert_eq!(Number::checked_pow(&3u64, &4), Some(81));
+        assert_eq!(Number::checked_pow(&2u64, &64), None);
+        assert_eq!(0b1100u64.xor(&0b1010), 0b0110);
+        assert_eq!(Number::checked_add(&u64::MAX, &1), None);
+        assert_eq!(Number::checked_mul(&(u64::MAX / 2), &3), None);
+        assert_eq!(1844674407370955161u64.checked_concat(&6, 10), None);
+        assert_eq!(
+            1844674407370955161u128.checked_concat(&6, 10),
+            Some(18446744073709551616)
+        );
+    }
+
+    #[test]
+    fn inverse_operations() {
+        assert_eq!(12345u64.strip_suffix(&345, 10), Some(12));
+        assert_eq!(12345u64.strip_suffix(&45, 10), Some(123));
+        assert_eq!(12345u64.strip_suffix(&44, 10), None);
+        assert_eq!(345u64.strip_suffix(&345, 10), Some(0));
+        assert_eq!(45u64.strip_suffix(&345, 10), None);
+        assert_eq!(50u64.strip_suffix(&0, 10), Some(5));
+        assert_eq!(u64::MAX.strip_suffix(&u64::MAX, 10), Some(0));
+        assert_eq!(0b10111u64.strip_suffix(&0b11, 2), Some(0b101));
+        assert_eq!(12u64.exact_div(&4), Some(3));
+        assert_eq!(12u64.exact_div(&5), None);
+        assert_eq!(12u64.exact_div(&0), None);
+        assert_eq!(Number::checked_sub(&3u64, &4), None);
+    }
+
+    #[test]
+    fn parse_located() {
+        let source = "1: 99999999999999999999";
+        let error = u64::parse(source, &source[3..]).unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((1, 4)));
+        assert_eq!(u128::parse(source, &source[3..]), Ok(99999999999999999999));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint_never_overflows() {
+        use num_bigint::BigUint;
+        let big = BigUint::from(u128::MAX);
+        assert_eq!(
+            big.checked_concat(&BigUint::from(7u8), 10)
+                .unwrap()
+                .to_string(),
+            format!("{}7", u128::MAX)
+        );
+        assert_eq!(
+            BigUint::from(12345u16).strip_suffix(&BigUint::from(345u16), 10),
+            Some(BigUint::from(12u8))
+        );
+        assert_eq!(
+            BigUint::from(345u16).strip_suffix(&BigUint::from(345u16), 10),
+            Some(BigUint::ZERO)
+        );
+    }
+}
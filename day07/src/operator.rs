@@ -0,0 +1,351 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::Number;
+
+/// Operator that can be placed between the numbers of an equation
+pub trait Operator<N: Number>: Sync {
+    /// Shown in explanations and used to select the operator with [`parse`]
+    fn symbol(&self) -> Cow<'static, str>;
+    /// `None` when the result does not fit in `N`
+    fn apply(&self, left: &N, right: &N) -> Option<N>;
+
+    /// Left operand giving `result` with `right`, `None` when there is none
+    ///
+    /// Only called when [`Operator::is_invertible`] is true.
+    fn undo(&self, _result: &N, _right: &N) -> Option<N> {
+        None
+    }
+
+    /// Whether any left operand gives `result` with `right`, like `x * 0 = 0`, so that the reverse
+    /// search accepts every value of the numbers before instead of calling [`Operator::undo`]
+    fn absorbs(&self, _result: &N, _right: &N) -> bool {
+        false
+    }
+
+    /// Whether the reverse search can undo the operator
+    fn is_invertible(&self) -> bool {
+        false
+    }
+
+    /// Whether the result with `right` is never below the left operand, so that the forward
+    /// searches can drop partial results above the target
+    ///
+    /// For a [monotone](Operator::is_monotone) operator this has to hold for every right operand
+    /// above `right` as well.
+    fn never_decreases(&self, _right: &N) -> bool {
+        false
+    }
+
+    /// Whether the result never gets smaller when either operand grows, the precedence search only
+    /// drops partial results above the target when every operator is
+    fn is_monotone(&self) -> bool {
+        false
+    }
+
+    /// Level in [`Precedence`] unless the table overrides it, higher binds tighter
+    fn precedence(&self) -> u8 {
+        1
+    }
+
+    /// How a chain of operators of the same precedence level is grouped
+    fn associativity(&self) -> Associativity {
+        Associativity::Left
+    }
+}
+
+/// Grouping of operators of the same precedence level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`
+    Right,
+}
+
+pub struct Add;
+
+impl<N: Number> Operator<N> for Add {
+    fn symbol(&self) -> Cow<'static, str> {
+        Cow::Borrowed("+")
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        left.checked_add(right)
+    }
+
+    fn undo(&self, result: &N, right: &N) -> Option<N> {
+        result.checked_sub(right)
+    }
+
+    fn is_invertible(&self) -> bool {
+        true
+    }
+
+    fn never_decreases(&self, _right: &N) -> bool {
+        true
+    }
+
+    fn is_monotone(&self) -> bool {
+        true
+    }
+}
+
+pub struct Mul;
+
+impl<N: Number> Operator<N> for Mul {
+    fn symbol(&self) -> Cow<'static, str> {
+        Cow::Borrowed("*")
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        left.checked_mul(right)
+    }
+
+    fn undo(&self, result: &N, right: &N) -> Option<N> {
+        result.exact_div(right)
+    }
+
+    fn absorbs(&self, result: &N, right: &N) -> bool {
+        *right == N::zero() && *result == N::zero()
+    }
+
+    fn is_invertible(&self) -> bool {
+        true
+    }
+
+    fn never_decreases(&self, right: &N) -> bool {
+        *right != N::zero()
+    }
+
+    fn is_monotone(&self) -> bool {
+        true
+    }
+
+    fn precedence(&self) -> u8 {
+        2
+    }
+}
+
+/// Digits of the left operand followed by the digits of the right one, in `base`
+pub struct Concat {
+    base: u32,
+}
+
+impl Concat {
+    pub const DECIMAL: Concat = Concat { base: 10 };
+
+    /// Concatenation in `base`, `None` unless it is within 2..=36
+    pub fn new(base: u32) -> Option<Self> {
+        (2..=36).contains(&base).then_some(Concat { base })
+    }
+}
+
+impl<N: Number> Operator<N> for Concat {
+    fn symbol(&self) -> Cow<'static, str> {
+        match self.base {
+            10 => Cow::Borrowed("||"),
+            base => Cow::Owned(format!("||{}", base)),
+        }
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        left.checked_concat(right, self.base)
+    }
+
+    fn undo(&self, result: &N, right: &N) -> Option<N> {
+        result.strip_suffix(right, self.base)
+    }
+
+    fn is_invertible(&self) -> bool {
+        true
+    }
+
+    fn never_decreases(&self, _right: &N) -> bool {
+        true
+    }
+
+    fn is_monotone(&self) -> bool {
+        true
+    }
+
+    fn precedence(&self) -> u8 {
+        0
+    }
+}
+
+/// Results below zero end the branch
+pub struct Sub;
+
+impl<N: Number> Operator<N> for Sub {
+    fn symbol(&self) -> Cow<'static, str> {
+        Cow::Borrowed("-")
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        left.checked_sub(right)
+    }
+
+    fn undo(&self, result: &N, right: &N) -> Option<N> {
+        result.checked_add(right)
+    }
+
+    fn is_invertible(&self) -> bool {
+        true
+    }
+}
+
+pub struct Xor;
+
+impl<N: Number> Operator<N> for Xor {
+    fn symbol(&self) -> Cow<'static, str> {
+        Cow::Borrowed("^")
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        Some(left.xor(right))
+    }
+
+    fn undo(&self, result: &N, right: &N) -> Option<N> {
+        Some(result.xor(right))
+    }
+
+    fn is_invertible(&self) -> bool {
+        true
+    }
+
+    fn precedence(&self) -> u8 {
+        0
+    }
+}
+
+/// Not invertible, equations using it are searched forward
+pub struct Pow;
+
+impl<N: Number> Operator<N> for Pow {
+    fn symbol(&self) -> Cow<'static, str> {
+        Cow::Borrowed("**")
+    }
+
+    fn apply(&self, left: &N, right: &N) -> Option<N> {
+        left.checked_pow(right)
+    }
+
+    fn precedence(&self) -> u8 {
+        3
+    }
+
+    fn associativity(&self) -> Associativity {
+        Associativity::Right
+    }
+}
+
+/// Operator with the given symbol: `+`, `*`, `||` (`||N` in base `N`), `-`, `^` (xor) or `**`
+pub fn parse<N: Number>(symbol: &str) -> Result<Box<dyn Operator<N>>, String> {
+    Ok(match symbol {
+        "+" => Box::new(Add),
+        "*" => Box::new(Mul),
+        "||" => Box::new(Concat::DECIMAL),
+        "-" => Box::new(Sub),
+        "^" => Box::new(Xor),
+        "**" => Box::new(Pow),
+        _ => {
+            let concat = symbol
+                .strip_prefix("||")
+                .and_then(|base| base.parse::<u32>().ok())
+                .and_then(Concat::new)
+                .ok_or_else(|| format!("unknown operator `{}`", symbol))?;
+            Box::new(concat)
+        }
+    })
+}
+
+/// Precedence levels of the operators by symbol, operators missing from the table keep their own
+/// [`Operator::precedence`]: `**` 3, `*` 2, `+` and `-` 1, `||` and `^` 0
+///
+/// Operators of the same level are grouped by the [`Operator::associativity`] of the later one, so
+/// only `**` groups from the right.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Precedence {
+    levels: HashMap<String, u8>,
+}
+
+impl Precedence {
+    pub fn with(mut self, symbol: &str, level: u8) -> Self {
+        self.levels.insert(symbol.to_string(), level);
+        self
+    }
+
+    pub fn level<N: Number>(&self, operator: &dyn Operator<N>) -> u8 {
+        self.levels
+            .get(operator.symbol().as_ref())
+            .copied()
+            .unwrap_or_else(|| operator.precedence())
+    }
+
+    /// Reads `symbol=level` entries like `||=3,+=2`
+    pub fn parse(entries: &[String]) -> Result<Self, String> {
+        entries
+            .iter()
+            .try_fold(Precedence::default(), |table, entry| {
+                let (symbol, level) = entry
+                    .split_once('=')
+                    .and_then(|(symbol, level)| Some((symbol, level.parse().ok()?)))
+                    .ok_or_else(|| {
+                        format!("precedence has to be `symbol=level`, not `{}`", entry)
+                    })?;
+                Ok(table.with(symbol, level))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_symbols() {
+        for symbol in ["+", "*", "||", "||2", "-", "^", "**"] {
+            assert_eq!(parse::<u64>(symbol).unwrap().symbol(), symbol);
+        }
+        assert_eq!(parse::<u64>("||10").unwrap().symbol(), "||");
+        assert!(parse::<u64>("||1").is_err());
+        assert!(parse::<u64>("/").is_err());
+        assert!(Concat::new(0).is_none());
+        assert!(Concat::new(37).is_none());
+    }
+
+    #[test]
+    fn undo_inverts_apply() {
+        let operators =
+            ["+", "*", "||", "||2", "||16", "-", "^"].map(|symbol| parse(symbol).unwrap());
+        for operator in &operators {
+            assert!(operator.is_invertible());
+            for (left, right) in [(12u64, 5u64), (7, 7), (1000, 3), (5, 1)] {
+                if let Some(result) = operator.apply(&left, &right) {
+                    assert_eq!(
+                        operator.undo(&result, &right),
+                        Some(left),
+                        "{} {} {}",
+                        left,
+                        operator.symbol(),
+                        right
+                    );
+                }
+            }
+        }
+        assert!(!Operator::<u64>::is_invertible(&Pow));
+        assert_eq!(Pow.apply(&3u64, &4), Some(81));
+    }
+
+    #[test]
+    fn precedence_table() {
+        let table = Precedence::parse(&["||=3".to_string(), "+=2".to_string()]).unwrap();
+        assert_eq!(table.level::<u64>(&Concat::DECIMAL), 3);
+        assert_eq!(table.level::<u64>(&Add), 2);
+        assert_eq!(table.level::<u64>(&Mul), 2);
+        assert_eq!(table.level::<u64>(&Concat::new(2).unwrap()), 0);
+        assert!(Precedence::parse(&["+".to_string()]).is_err());
+        assert!(Precedence::parse(&["+=x".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{Coord, Grid, ParseError, Point, Solution};
+
+type Antenas = HashMap<char, Vec<Point>>;
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Antenas), ParseError> {
+    let grid = Grid::from_chars(input, |c| c)?;
+    let antenas = grid.iter().filter(|(_, &cell)| cell != '.').fold(
+        HashMap::new(),
+        |mut antenas: Antenas, (coord, &cell)| {
+            antenas.entry(cell).or_default().push(coord.into());
+            antenas
+        },
+    );
+    Ok((grid, antenas))
+}
+
+fn is_on_grid(grid: &Grid<char>, point: Point) -> bool {
+    Coord::try_from(point).is_ok_and(|coord| grid.contains(coord))
+}
+
+fn generate_antinodes(antenas: &[Point], grid: &Grid<char>) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+    for first in 0..antenas.len() {
+        for second in first + 1..antenas.len() {
+            let first = antenas[first];
+            let second = antenas[second];
+            let diff = second - first;
+            for antinode in [first - diff, second + diff] {
+                if is_on_grid(grid, antinode) {
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+    antinodes
+}
+
+fn generate_antinodes_with_harmonics(antenas: &[Point], grid: &Grid<char>) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+    for first in 0..antenas.len() {
+        for second in first + 1..antenas.len() {
+            let mut first = antenas[first];
+            let mut second = antenas[second];
+            let diff = second - first;
+            while is_on_grid(grid, first) {
+                antinodes.insert(first);
+                first -= diff;
+            }
+            while is_on_grid(grid, second) {
+                antinodes.insert(second);
+                second += diff;
+            }
+        }
+    }
+    antinodes
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = (Grid<char>, Antenas);
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part((grid, data): &Self::Input) -> usize {
+        data.values()
+            .fold(HashSet::new(), |mut antinodes, antenas| {
+                antinodes.extend(generate_antinodes(antenas, grid));
+                antinodes
+            })
+            .len()
+    }
+
+    fn second_part((grid, data): &Self::Input) -> usize {
+        data.values()
+            .fold(HashSet::new(), |mut antinodes, antenas| {
+                antinodes.extend(generate_antinodes_with_harmonics(antenas, grid));
+                antinodes
+            })
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day08, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day08, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day08, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day08, "input", Part::Second);
+    }
+}
@@ -0,0 +1,177 @@
+use std::iter;
+use utils::{ParseError, Solution, StrParser};
+
+type FileEmpty = (usize, usize);
+#[derive(Clone, Copy)]
+struct File {
+    pub size: usize,
+    pub index: usize,
+}
+
+fn parse_digit((index, c): (usize, char)) -> Result<usize, ParseError> {
+    c.try_parse_usize().map_err(|error| error.at(1, index + 1))
+}
+
+fn parse_input(input: &str) -> Result<Vec<FileEmpty>, ParseError> {
+    let mut result = Vec::new();
+    let mut chars = input.trim_end().chars().enumerate();
+    while let Some(c) = chars.next() {
+        let first = parse_digit(c)?;
+        let second = if let Some(next_char) = chars.next() {
+            parse_digit(next_char)?
+        } else {
+            0
+        };
+        result.push((first, second));
+    }
+
+    Ok(result)
+}
+
+fn expand_and_defragment(data: &[FileEmpty]) -> Vec<usize> {
+    let mut result = vec![];
+    if data.len() <= 1 {
+        return result;
+    }
+    let mut index = 0;
+    let mut last_index = data.len() - 1;
+    let (mut files, mut empty) = data.get(index).unwrap();
+    let (mut files_last, _) = data.get(last_index).unwrap();
+    while index < last_index {
+        result.extend(iter::repeat_n(index, files));
+        files = 0;
+        if files_last == 0 {
+            last_index -= 1;
+            (files_last, _) = *data.get(last_index).unwrap();
+            continue;
+        }
+        if empty > files_last {
+            result.extend(iter::repeat_n(last_index, files_last));
+            empty -= files_last;
+            last_index -= 1;
+            (files_last, _) = *data.get(last_index).unwrap();
+            continue;
+        } else {
+            result.extend(iter::repeat_n(last_index, empty));
+            files_last -= empty;
+            index += 1;
+            (files, empty) = *data.get(index).unwrap()
+        }
+    }
+    if files_last > 0 {
+        result.extend(iter::repeat_n(last_index, files_last));
+    }
+
+    result
+}
+
+fn expand_and_move_files(data: &[FileEmpty]) -> Vec<usize> {
+    let mut data = data
+        .iter()
+        .enumerate()
+        .map(|(index, file_empty)| {
+            (
+                0,
+                vec![File {
+                    size: file_empty.0,
+                    index,
+                }],
+                file_empty.1,
+            )
+        })
+        .collect::<Vec<_>>();
+    if data.len() <= 1 {
+        return vec![];
+    }
+    let mut last_index = data.len() - 1;
+    while last_index > 0 {
+        let (_, files, _) = data.get(last_index).unwrap().clone();
+        let file = *files.first().unwrap();
+        let mut index = 0;
+        let mut success = false;
+        while index < last_index {
+            let (begin_empty, mut begin_files, end_empty) = data.get(index).unwrap().clone();
+            if end_empty >= file.size {
+                begin_files.push(file);
+                success = true;
+                data[index] = (begin_empty, begin_files, end_empty - file.size);
+                break;
+            }
+            index += 1;
+        }
+        if success {
+            let tmp = data.get_mut(last_index).unwrap();
+            tmp.1.remove(0);
+            data[last_index] = (tmp.0 + file.size, tmp.1.clone(), tmp.2);
+        }
+        last_index -= 1;
+    }
+    data.iter()
+        .fold(vec![], |mut data, (begin_empty, files, end_empty)| {
+            data.extend(iter::repeat_n(0, *begin_empty));
+            for file in files {
+                data.extend(iter::repeat_n(file.index, file.size));
+            }
+            data.extend(iter::repeat_n(0, *end_empty));
+            data
+        })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Vec<FileEmpty>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part(data: &Self::Input) -> usize {
+        let data = expand_and_defragment(data);
+        data.iter()
+            .enumerate()
+            .fold(0, |sum, (file_index, block_index)| {
+                sum + file_index * *block_index
+            })
+    }
+
+    fn second_part(data: &Self::Input) -> usize {
+        let data = expand_and_move_files(data);
+        data.iter()
+            .enumerate()
+            .fold(0, |sum, (file_index, block_index)| {
+                sum + file_index * *block_index
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day09, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day09, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day09, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day09, "input", Part::Second);
+    }
+}
@@ -0,0 +1,72 @@
+use utils::search::{bfs, count_paths};
+use utils::{Coord, Grid, ParseError, Solution, StrParser};
+
+fn uphill_steps(grid: &Grid<usize>, cell: Coord) -> impl Iterator<Item = Coord> + '_ {
+    cell.neighbours(grid.width(), grid.height())
+        .filter(move |&next_cell| grid[cell] + 1 == grid[next_cell])
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Grid<usize>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::try_from_chars(input, |_, c| c.try_parse_usize())
+    }
+
+    fn first_part(grid: &Self::Input) -> usize {
+        grid.find_all(|&height| height == 0)
+            .map(|trailhead| {
+                bfs(trailhead, |&cell| uphill_steps(grid, cell))
+                    .reached()
+                    .filter(|&&cell| grid[cell] == 9)
+                    .count()
+            })
+            .sum()
+    }
+
+    fn second_part(grid: &Self::Input) -> usize {
+        grid.find_all(|&height| height == 0)
+            .map(|trailhead| {
+                count_paths(
+                    trailhead,
+                    |&cell| uphill_steps(grid, cell),
+                    |&cell| grid[cell] == 9,
+                )
+                .expect("trails only go uphill, so they cannot loop")
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    fn test_first_part() {
+        utils::check_answer!(Day10, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!(Day10, "input", Part::First);
+    }
+
+    #[test]
+    fn test_second_part() {
+        utils::check_answer!(Day10, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!(Day10, "input", Part::Second);
+    }
+}
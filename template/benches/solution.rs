@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use {{crate_name}}::{{project-name | capitalize}};
+use utils::Solution;
+
+fn solution(c: &mut Criterion) {
+    let Some(input) = utils::input::private_input({{project-name | capitalize}}::DAY, env!("CARGO_MANIFEST_DIR")) else {
+        return;
+    };
+    let model = {{project-name | capitalize}}::parse(&input).expect("personal input is valid");
+    c.bench_function("parse", |b| b.iter(|| {{project-name | capitalize}}::parse(black_box(&input))));
+    c.bench_function("first part", |b| {
+        b.iter(|| {{project-name | capitalize}}::first_part(black_box(&model)))
+    });
+    c.bench_function("second part", |b| {
+        b.iter(|| {{project-name | capitalize}}::second_part(black_box(&model)))
+    });
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use utils::{ParseError, Solution};
+
+/// Parsed puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Model {
+    pub lines: Vec<String>,
+}
+
+fn parse_input(input: &str) -> Result<Model, ParseError> {
+    Ok(Model {
+        lines: input.lines().map(str::to_string).collect(),
+    })
+}
+
+pub struct {{project-name | capitalize}};
+
+impl Solution for {{project-name | capitalize}} {
+    const DAY: u8 = {{project-name | remove: "day" | plus: 0}};
+    const TITLE: &'static str = "TODO";
+
+    type Input = Model;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn first_part(_model: &Self::Input) -> usize {
+        todo!()
+    }
+
+    fn second_part(_model: &Self::Input) -> usize {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    #[test]
+    #[ignore = "record the answer of the example in answers.txt"]
+    fn test_first_part() {
+        utils::check_answer!({{project-name | capitalize}}, "test", Part::First);
+    }
+
+    #[test]
+    fn input_first_part() {
+        utils::check_answer!({{project-name | capitalize}}, "input", Part::First);
+    }
+
+    #[test]
+    #[ignore = "record the answer of the example in answers.txt"]
+    fn test_second_part() {
+        utils::check_answer!({{project-name | capitalize}}, "test", Part::Second);
+    }
+
+    #[test]
+    fn input_second_part() {
+        utils::check_answer!({{project-name | capitalize}}, "input", Part::Second);
+    }
+}
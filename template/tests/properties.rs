@@ -0,0 +1,11 @@
+use {{crate_name}}::{{project-name | capitalize}};
+use proptest::prelude::*;
+use utils::Solution;
+
+proptest! {
+    /// Malformed input has to be reported as a `ParseError`, never as a panic
+    #[test]
+    fn parse_never_panics(input in "[0-9a-z#.,| \n-]{0,200}") {
+        let _ = {{project-name | capitalize}}::parse(&input);
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input::{input_dir_is_set, private_input, InputSource, INPUT_DIR_ENV};
+use crate::parser::ParseError;
+use crate::solution::{Part, Solution, ToAnswer};
+
+/// File with the known answers, stored next to `Cargo.toml` of every day
+pub const ANSWERS_FILE: &str = "answers.txt";
+/// Input name of the personal input, other names are files `inputs/<name>.txt`
+pub const PRIVATE_INPUT: &str = "input";
+
+const HEADER: &str = "# input part answer";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+}
+
+/// Known answers of one day, one `input part answer` line per entry, `#` starts a comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    path: PathBuf,
+    comments: Vec<String>,
+    entries: Vec<Answer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, error) => {
+                write!(f, "cannot access {}: {}", path.display(), error)
+            }
+            AnswersError::Parse(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    /// Answers of the day in `crate_dir`, a missing file means no answers are known yet
+    pub fn load(crate_dir: &Path) -> Result<Self, AnswersError> {
+        let path = crate_dir.join(ANSWERS_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(AnswersError::Io(path, error)),
+        };
+        Answers::from_content(path.clone(), &content)
+            .map_err(|error| AnswersError::Parse(path, error))
+    }
+
+    fn from_content(path: PathBuf, content: &str) -> Result<Self, ParseError> {
+        Ok(Answers {
+            path,
+            comments: content
+                .lines()
+                .filter(|line| line.trim_start().starts_with('#'))
+                .map(str::to_string)
+                .collect(),
+            entries: Answers::parse(content)?,
+        })
+    }
+
+    fn parse(content: &str) -> Result<Vec<Answer>, ParseError> {
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|line| {
+                let error = |reason: &str| ParseError::new(line, reason).locate(content, line);
+                let [input, part, expected] = line.split_whitespace().collect::<Vec<_>>()[..]
+                else {
+                    return Err(error("answer has to be in format `input part answer`"));
+                };
+                let part = part
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(|part| Part::try_from(part).ok())
+                    .ok_or_else(|| error("part has to be 1 or 2"))?;
+                Ok(Answer {
+                    input: input.to_string(),
+                    part,
+                    expected: expected.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &[Answer] {
+        &self.entries
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|answer| answer.input == input && answer.part == part)
+            .map(|answer| answer.expected.as_str())
+    }
+
+    /// Sets the answer, returns the previous one when it differs
+    pub fn record(&mut self, input: &str, part: Part, expected: &str) -> Option<String> {
+        match self
+            .entries
+            .iter_mut()
+            .find(|answer| answer.input == input && answer.part == part)
+        {
+            Some(answer) => Some(std::mem::replace(
+                &mut answer.expected,
+                expected.to_string(),
+            ))
+            .filter(|previous| previous != expected),
+            None => {
+                self.entries.push(Answer {
+                    input: input.to_string(),
+                    part,
+                    expected: expected.to_string(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Writes the answers back, comments are kept above them and a new file gets a header
+    pub fn save(&self) -> Result<(), AnswersError> {
+        fs::write(&self.path, self.render())
+            .map_err(|error| AnswersError::Io(self.path.clone(), error))
+    }
+
+    fn render(&self) -> String {
+        let width = self
+            .entries
+            .iter()
+            .map(|answer| answer.input.len())
+            .max()
+            .unwrap_or_default();
+        let mut content = match self.comments.is_empty() {
+            true => format!("{}\n", HEADER),
+            false => self.comments.join("\n") + "\n",
+        };
+        for answer in &self.entries {
+            content += &format!(
+                "{:<width$} {} {}\n",
+                answer.input, answer.part, answer.expected
+            );
+        }
+        content
+    }
+}
+
+/// Where the input with the given name is read from, see [`PRIVATE_INPUT`]
+pub fn input_source(day: u8, input: &str, crate_dir: &Path) -> InputSource {
+    match input {
+        PRIVATE_INPUT => InputSource::resolve(day, None, crate_dir),
+        name => InputSource::File(crate_dir.join("inputs").join(format!("{}.txt", name))),
+    }
+}
+
+/// Solves one part of `S` and returns the answer as it is printed
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, String> {
+    let error = |error: ParseError| format!("invalid input, {}", error);
+    match part {
+        Part::First => S::solve_first_part(input).map_err(error)?.to_answer(),
+        Part::Second => S::solve_second_part(input).map_err(error)?.to_answer(),
+    }
+}
+
+/// Compares the answer of `S` on the named input with the recorded one
+///
+/// The check is skipped when the answer is not recorded yet, so a new day starts green, and for
+/// the personal input when it is missing or comes from `AOC_INPUT_DIR` (the answers are recorded
+/// for your own input).
+pub fn check<S: Solution>(crate_dir: &str, input: &str, part: Part) {
+    let answers = Answers::load(Path::new(crate_dir)).unwrap_or_else(|error| panic!("{}", error));
+    let private = match input {
+        PRIVATE_INPUT if input_dir_is_set() => {
+            eprintln!(
+                "Skipping test: the input from {} is not checked against {}",
+                INPUT_DIR_ENV, ANSWERS_FILE
+            );
+            return;
+        }
+        PRIVATE_INPUT => match private_input(S::DAY, crate_dir) {
+            Some(data) => Some(data),
+            None => return,
+        },
+        _ => None,
+    };
+    let expected = match answers.get(input, part) {
+        Some(expected) => expected,
+        None if private.is_some() => {
+            eprintln!(
+                "Skipping test: no answer of part {} for `{}` in {}",
+                part,
+                input,
+                answers.path().display()
+            );
+            return;
+        }
+        None => panic!(
+            "no answer of part {} for `{}` in {}",
+            part,
+            input,
+            answers.path().display()
+        ),
+    };
+    let data = private.unwrap_or_else(|| {
+        input_source(S::DAY, input, Path::new(crate_dir))
+            .read()
+            .unwrap_or_else(|error| panic!("{}", error))
+    });
+    let actual = solve::<S>(&data, part).unwrap_or_else(|error| panic!("{}", error));
+    assert_eq!(actual, expected, "part {} of `{}`", part, input);
+}
+
+/// Checks the answer of the given day recorded in `answers.txt` of the calling crate.
+#[macro_export]
+macro_rules! check_answer {
+    ($day:ty, $input:expr, $part:expr) => {
+        $crate::answers::check::<$day>(env!("CARGO_MANIFEST_DIR"), $input, $part)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "# input part answer\ntest  1 11\ninput 1 2057374\n\ntest  2 31\n";
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::from_content(PathBuf::new(), ANSWERS).unwrap();
+        assert_eq!(answers.entries().len(), 3);
+        assert_eq!(answers.get("input", Part::First), Some("2057374"));
+        assert_eq!(answers.get("test", Part::Second), Some("31"));
+        assert_eq!(answers.get("input", Part::Second), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Answers::parse("test 1 11\ntest 3 31\n").unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 1)));
+        assert!(Answers::parse("test 1\n").is_err());
+    }
+
+    #[test]
+    fn record_answers() {
+        let mut answers = Answers::from_content(PathBuf::new(), ANSWERS).unwrap();
+        assert_eq!(answers.record("input", Part::Second, "42"), None);
+        assert_eq!(answers.record("input", Part::Second, "42"), None);
+        assert_eq!(
+            answers.record("test", Part::First, "12"),
+            Some("11".to_string())
+        );
+        assert_eq!(answers.get("input", Part::Second), Some("42"));
+        assert_eq!(answers.get("test", Part::First), Some("12"));
+    }
+
+    #[test]
+    fn render_keeps_comments() {
+        let mut answers =
+            Answers::from_content(PathBuf::new(), "# input part answer\n# a hint\n").unwrap();
+        answers.record("input", Part::First, "42");
+        assert_eq!(
+            answers.render(),
+            "# input part answer\n# a hint\ninput 1 42\n"
+        );
+        let answers = Answers::from_content(PathBuf::new(), "test 1 11\n").unwrap();
+        assert_eq!(answers.render(), "# input part answer\ntest 1 11\n");
+    }
+}
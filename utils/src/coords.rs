@@ -1,6 +1,6 @@
 use std::ops::{Add, Sub};
 
-use crate::Direction;
+use crate::{Direction, Direction8};
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct Coord {
@@ -48,6 +48,43 @@ impl Add<Direction> for Coord {
     }
 }
 
+impl Add<Direction8> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl Coord {
+    fn neighbours_in(
+        self,
+        directions: &'static [Direction8],
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Coord> {
+        directions
+            .iter()
+            .filter_map(move |&direction| self + direction)
+            .filter(move |coord| coord.x < width && coord.y < height)
+    }
+
+    /// Up, right, down and left neighbours inside of the `width` x `height` area
+    pub fn neighbours(self, width: usize, height: usize) -> impl Iterator<Item = Coord> {
+        self.neighbours_in(&Direction8::ORTHOGONAL, width, height)
+    }
+
+    /// Diagonal neighbours inside of the `width` x `height` area
+    pub fn diagonal_neighbours(self, width: usize, height: usize) -> impl Iterator<Item = Coord> {
+        self.neighbours_in(&Direction8::DIAGONAL, width, height)
+    }
+
+    /// All eight neighbours inside of the `width` x `height` area
+    pub fn all_neighbours(self, width: usize, height: usize) -> impl Iterator<Item = Coord> {
+        self.neighbours_in(&Direction8::ALL, width, height)
+    }
+}
+
 impl From<(usize, usize)> for Coord {
     fn from(value: (usize, usize)) -> Self {
         Coord {
@@ -56,3 +93,38 @@ impl From<(usize, usize)> for Coord {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(neighbours: impl Iterator<Item = Coord>) -> Vec<(usize, usize)> {
+        neighbours.map(|c| (c.x, c.y)).collect()
+    }
+
+    #[test]
+    fn neighbours_in_the_middle() {
+        let coord = Coord { x: 1, y: 1 };
+        assert_eq!(
+            collect(coord.neighbours(3, 3)),
+            [(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+        assert_eq!(
+            collect(coord.diagonal_neighbours(3, 3)),
+            [(2, 0), (2, 2), (0, 2), (0, 0)]
+        );
+        assert_eq!(coord.all_neighbours(3, 3).count(), 8);
+    }
+
+    #[test]
+    fn neighbours_on_the_edge() {
+        assert_eq!(
+            collect(Coord { x: 0, y: 0 }.neighbours(3, 2)),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            collect(Coord { x: 2, y: 1 }.all_neighbours(3, 2)),
+            [(2, 0), (1, 1), (1, 0)]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::Add;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Direction {
     Up,
     Right,
@@ -8,6 +8,121 @@ pub enum Direction {
     Left,
 }
 
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Change of `(x, y)` after one step, `y` grows downwards
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Compass direction including diagonals, `North` is the same as [`Direction::Up`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions clockwise from `North`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+    pub const ORTHOGONAL: [Direction8; 4] = [
+        Direction8::North,
+        Direction8::East,
+        Direction8::South,
+        Direction8::West,
+    ];
+    pub const DIAGONAL: [Direction8; 4] = [
+        Direction8::NorthEast,
+        Direction8::SouthEast,
+        Direction8::SouthWest,
+        Direction8::NorthWest,
+    ];
+
+    /// Change of `(x, y)` after one step, `y` grows downwards
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        Direction8::DIAGONAL.contains(&self)
+    }
+
+    /// Rotates by `steps` eighths of a full turn clockwise (negative is counterclockwise)
+    pub fn rotate(self, steps: isize) -> Direction8 {
+        let index = Direction8::ALL.iter().position(|&d| d == self).unwrap() as isize;
+        Direction8::ALL[(index + steps).rem_euclid(8) as usize]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Direction8::North,
+            Direction::Right => Direction8::East,
+            Direction::Down => Direction8::South,
+            Direction::Left => Direction8::West,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = ();
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::North => Ok(Direction::Up),
+            Direction8::East => Ok(Direction::Right),
+            Direction8::South => Ok(Direction::Down),
+            Direction8::West => Ok(Direction::Left),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Add<Turn> for Direction8 {
+    type Output = Direction8;
+
+    fn add(self, rhs: Turn) -> Self::Output {
+        match rhs {
+            Turn::Straight => self,
+            Turn::Left => self.rotate(-2),
+            Turn::TurnOver => self.rotate(4),
+            Turn::Right => self.rotate(2),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Turn {
     Straight,
@@ -57,3 +172,36 @@ impl TryFrom<char> for Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_direction8() {
+        assert_eq!(Direction8::NorthEast + Turn::Right, Direction8::SouthEast);
+        assert_eq!(Direction8::North + Turn::Left, Direction8::West);
+        assert_eq!(
+            Direction8::SouthWest + Turn::TurnOver,
+            Direction8::NorthEast
+        );
+        assert_eq!(Direction8::NorthWest.rotate(1), Direction8::North);
+    }
+
+    #[test]
+    fn direction8_matches_direction() {
+        for direction in Direction::ALL {
+            for turn in [Turn::Straight, Turn::Left, Turn::TurnOver, Turn::Right] {
+                assert_eq!(
+                    Direction8::from(direction) + turn,
+                    Direction8::from(direction + turn)
+                );
+            }
+            assert_eq!(
+                Direction::try_from(Direction8::from(direction)),
+                Ok(direction)
+            );
+        }
+        assert_eq!(Direction::try_from(Direction8::SouthEast), Err(()));
+    }
+}
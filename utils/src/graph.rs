@@ -0,0 +1,221 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+
+/// Nodes forming a cycle, each node has an edge to the next one and the last one to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    pub nodes: Vec<N>,
+}
+
+impl<N: Debug> Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle through {:?}", self.nodes)
+    }
+}
+
+impl<N: Debug> std::error::Error for CycleError<N> {}
+
+/// Orders `nodes` so every edge `(before, after)` points forward (Kahn's algorithm)
+///
+/// Nodes appearing only in `edges` are included, unconstrained nodes keep their input order.
+pub fn topological_sort<N>(
+    nodes: impl IntoIterator<Item = N>,
+    edges: impl IntoIterator<Item = (N, N)>,
+) -> Result<Vec<N>, CycleError<N>>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut indices = HashMap::new();
+    let mut order = vec![];
+    let mut index_of = |node: N| {
+        *indices.entry(node.clone()).or_insert_with(|| {
+            order.push(node);
+            order.len() - 1
+        })
+    };
+    nodes.into_iter().for_each(|node| {
+        index_of(node);
+    });
+    let edges = edges
+        .into_iter()
+        .map(|(before, after)| (index_of(before), index_of(after)))
+        .collect::<HashSet<_>>();
+
+    let mut successors = vec![vec![]; order.len()];
+    let mut incoming = vec![0; order.len()];
+    for &(before, after) in &edges {
+        successors[before].push(after);
+        incoming[after] += 1;
+    }
+    successors.iter_mut().for_each(|next| next.sort_unstable());
+
+    let mut queue = (0..order.len())
+        .filter(|&node| incoming[node] == 0)
+        .collect::<VecDeque<_>>();
+    let mut sorted = Vec::with_capacity(order.len());
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node);
+        for &next in &successors[node] {
+            incoming[next] -= 1;
+            if incoming[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+    if sorted.len() < order.len() {
+        return Err(CycleError {
+            nodes: find_cycle(&incoming, &edges)
+                .into_iter()
+                .map(|node| order[node].clone())
+                .collect(),
+        });
+    }
+    Ok(sorted.into_iter().map(|node| order[node].clone()).collect())
+}
+
+/// Walks backwards over nodes left by Kahn's algorithm, each of them has a predecessor among them
+fn find_cycle(incoming: &[usize], edges: &HashSet<(usize, usize)>) -> Vec<usize> {
+    let mut predecessor = HashMap::new();
+    for &(before, after) in edges {
+        if incoming[before] > 0 && incoming[after] > 0 {
+            predecessor.entry(after).or_insert(before);
+        }
+    }
+    let mut node = (0..incoming.len())
+        .find(|&node| incoming[node] > 0)
+        .unwrap();
+    let mut seen = vec![];
+    while !seen.contains(&node) {
+        seen.push(node);
+        node = predecessor[&node];
+    }
+    let start = seen.iter().position(|&seen| seen == node).unwrap();
+    let mut cycle = seen.split_off(start);
+    cycle.reverse();
+    cycle
+}
+
+/// Set of `(before, after)` rules, only the listed pairs are ordered
+#[derive(Debug, Clone, Default)]
+pub struct PartialOrder<N> {
+    edges: HashSet<(N, N)>,
+}
+
+impl<N: Clone + Eq + Hash> PartialOrder<N> {
+    pub fn new() -> Self {
+        PartialOrder {
+            edges: HashSet::new(),
+        }
+    }
+
+    pub fn add(&mut self, before: N, after: N) {
+        self.edges.insert((before, after));
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &(N, N)> {
+        self.edges.iter()
+    }
+
+    /// Total order of `items` consistent with the rules between them, for `sort_by`
+    pub fn rank(&self, items: &[N]) -> Result<Rank<N>, CycleError<N>> {
+        Ok(Rank {
+            positions: self
+                .sort_subset(items)?
+                .into_iter()
+                .enumerate()
+                .map(|(position, item)| (item, position))
+                .collect(),
+        })
+    }
+
+    /// True when no rule is broken by any pair of `items`, not only neighbouring ones
+    pub fn is_sorted(&self, items: &[N]) -> bool {
+        let position = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (item, index))
+            .collect::<HashMap<_, _>>();
+        self.edges.iter().all(
+            |(before, after)| match (position.get(before), position.get(after)) {
+                (Some(before), Some(after)) => before < after,
+                _ => true,
+            },
+        )
+    }
+
+    /// Topologically sorts `items` using only the rules between them
+    pub fn sort_subset(&self, items: &[N]) -> Result<Vec<N>, CycleError<N>> {
+        let items_set = items.iter().collect::<HashSet<_>>();
+        topological_sort(
+            items.iter().cloned(),
+            self.edges
+                .iter()
+                .filter(|(before, after)| items_set.contains(before) && items_set.contains(after))
+                .cloned(),
+        )
+    }
+}
+
+/// Positions of the items of a topologically sorted subset
+#[derive(Debug, Clone)]
+pub struct Rank<N> {
+    positions: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash> Rank<N> {
+    /// Compares by position in the sorted subset, nodes outside of it come last
+    pub fn compare(&self, a: &N, b: &N) -> Ordering {
+        let position = |node| self.positions.get(node).copied().unwrap_or(usize::MAX);
+        position(a).cmp(&position(b))
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for PartialOrder<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        PartialOrder {
+            edges: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_by_edges() {
+        let sorted = topological_sort([5, 4, 3, 2, 1], [(1, 2), (2, 3), (1, 4)]).unwrap();
+        assert_eq!(sorted, [5, 1, 4, 2, 3]);
+        let sorted = topological_sort([], [("a", "b"), ("c", "a")]).unwrap();
+        assert_eq!(sorted, ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn reports_cycle() {
+        let error = topological_sort([0], [(0, 1), (1, 2), (2, 3), (3, 1)]).unwrap_err();
+        assert_eq!(error.nodes.len(), 3);
+        let edges = [(1, 2), (2, 3), (3, 1)];
+        for (index, node) in error.nodes.iter().enumerate() {
+            let next = error.nodes[(index + 1) % error.nodes.len()];
+            assert!(edges.contains(&(*node, next)));
+        }
+        assert!(error.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn partial_order() {
+        let order = PartialOrder::from_iter([(1, 2), (2, 3), (3, 4), (9, 1)]);
+        let rank = order.rank(&[3, 1, 5, 2]).unwrap();
+        assert_eq!(rank.compare(&1, &2), Ordering::Less);
+        assert_eq!(rank.compare(&3, &2), Ordering::Greater);
+        assert_eq!(rank.compare(&1, &3), Ordering::Less);
+        assert_eq!(rank.compare(&5, &5), Ordering::Equal);
+        assert_eq!(rank.compare(&4, &5), Ordering::Greater);
+        assert!(order.is_sorted(&[1, 2, 3]));
+        assert!(order.is_sorted(&[1, 7, 4]));
+        assert!(!order.is_sorted(&[2, 7, 1]));
+        assert_eq!(order.sort_subset(&[4, 7, 2, 1]).unwrap(), [4, 7, 1, 2]);
+    }
+}
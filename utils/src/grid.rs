@@ -0,0 +1,238 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{Coord, ParseError};
+
+/// Rectangular grid stored row by row in a single buffer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid {}x{} needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a character map, every line is one row and has to be of the same length
+    pub fn from_chars(input: &str, mut map: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Grid::try_from_chars(input, |_, c| Ok(map(c)))
+    }
+
+    /// Parses a character map with a fallible mapping, errors without position are located at the character
+    pub fn try_from_chars(
+        input: &str,
+        mut map: impl FnMut(Coord, char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let line_width = line.chars().count();
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(ParseError::new(
+                    line,
+                    format!(
+                        "row has {} cells but the first row has {}",
+                        line_width,
+                        width.unwrap_or_default()
+                    ),
+                )
+                .locate(input, line));
+            }
+            for (x, c) in line.chars().enumerate() {
+                let cell = map(Coord { x, y }, c).map_err(|error| match error.position() {
+                    Some(_) => error,
+                    None => error.at(y + 1, x + 1),
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then_some(coord.y * self.width + coord.x)
+    }
+
+    fn coord_of(&self, index: usize) -> Coord {
+        Coord {
+            x: index % self.width,
+            y: index / self.width,
+        }
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on zero width, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.cells.len()).map(|index| self.coord_of(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coord_of(index), cell))
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn map<U>(&self, map: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(map).collect(),
+        )
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of the {}x{} grid",
+                coord, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", coord, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StrParser;
+
+    const MAP: &str = "#.#\n..#\n";
+
+    #[test]
+    fn parse_and_get() {
+        let grid = Grid::from_chars(MAP, |c| c == '#').unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Coord { x: 2, y: 1 }), Some(&true));
+        assert!(!grid[Coord { x: 1, y: 0 }]);
+        assert_eq!(grid.get(Coord { x: 3, y: 0 }), None);
+        assert_eq!(grid.get(Coord { x: 0, y: 2 }), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Grid::from_chars("###\n##\n", |c| c).unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 1)));
+        let error = Grid::try_from_chars("12\n3x", |_, c| c.try_parse_u8()).unwrap_err();
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 2)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_chars(MAP, |c| c).unwrap();
+        let rows = grid
+            .rows()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, ["#.#", "..#"]);
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["#.", "..", "##"]);
+    }
+
+    #[test]
+    fn find_and_modify() {
+        let mut grid = Grid::from_chars(MAP, |c| c).unwrap();
+        grid[Coord { x: 1, y: 1 }] = '#';
+        let walls = grid.find_all(|&c| c == '#').collect::<Vec<_>>();
+        assert_eq!(
+            walls,
+            [(0, 0), (2, 0), (1, 1), (2, 1)].map(Coord::from).to_vec()
+        );
+        assert_eq!(grid.to_string(), "#.#\n.##\n");
+    }
+
+    #[test]
+    fn empty_grid() {
+        let grid = Grid::from_chars("", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+}
@@ -0,0 +1,105 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory with personal inputs named `dayXX.txt`, takes precedence over `inputs/input.txt` of the day crate
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(
+                f,
+                "input {} not found (pass a path, `-` for stdin or set {})",
+                path.display(),
+                INPUT_DIR_ENV
+            ),
+            InputError::Io(path, error) => write!(f, "cannot read {}: {}", path.display(), error),
+            InputError::Stdin(error) => write!(f, "cannot read stdin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl InputSource {
+    /// Explicit argument (`-` is stdin) wins, then `$AOC_INPUT_DIR/dayXX.txt`, then `inputs/input.txt` in `crate_dir`.
+    pub fn resolve(day: u8, argument: Option<&str>, crate_dir: &Path) -> InputSource {
+        match argument {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(default_path(day, crate_dir)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|error| {
+                if error.kind() == io::ErrorKind::NotFound {
+                    InputError::NotFound(path.clone())
+                } else {
+                    InputError::Io(path.clone(), error)
+                }
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn default_path(day: u8, crate_dir: &Path) -> PathBuf {
+    match env::var_os(INPUT_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir).join(format!("day{:02}.txt", day)),
+        None => crate_dir.join("inputs").join("input.txt"),
+    }
+}
+
+/// Whether the personal inputs come from [`INPUT_DIR_ENV`], which may hold someone else's inputs
+pub fn input_dir_is_set() -> bool {
+    env::var_os(INPUT_DIR_ENV).is_some()
+}
+
+/// Personal input for tests, `None` when it is not checked out so that the test can be skipped.
+pub fn private_input(day: u8, crate_dir: &str) -> Option<String> {
+    match InputSource::resolve(day, None, Path::new(crate_dir)).read() {
+        Ok(input) => Some(input),
+        Err(error) => {
+            eprintln!("Skipping test: {}", error);
+            None
+        }
+    }
+}
+
+/// Returns the personal input of the given day from the calling test or skips the test when it is missing.
+#[macro_export]
+macro_rules! require_private_input {
+    ($day:ty) => {
+        match $crate::input::private_input(
+            <$day as $crate::Solution>::DAY,
+            env!("CARGO_MANIFEST_DIR"),
+        ) {
+            Some(input) => input,
+            None => return,
+        }
+    };
+}
@@ -1,7 +1,18 @@
+pub mod answers;
 pub mod coords;
 pub mod direction;
+pub mod graph;
+pub mod grid;
+pub mod input;
 pub mod parser;
+pub mod point;
+pub mod search;
+pub mod solution;
 
 pub use coords::Coord;
-pub use direction::{Direction, Turn};
-pub use parser::StrParser;
+pub use direction::{Direction, Direction8, Turn};
+pub use grid::Grid;
+pub use input::InputSource;
+pub use parser::{ParseError, StrParser};
+pub use point::{Point, Vector};
+pub use solution::{run, AnySolution, Part, Solution, ToAnswer};
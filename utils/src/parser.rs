@@ -1,14 +1,207 @@
+use std::any::type_name;
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    text: String,
+    reason: String,
+    position: Option<Position>,
+}
+
+impl ParseError {
+    pub fn new(text: impl Into<String>, reason: impl Into<String>) -> Self {
+        ParseError {
+            text: text.into(),
+            reason: reason.into(),
+            position: None,
+        }
+    }
+
+    /// Sets 1-based line and column of the offending text
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(Position { line, column });
+        self
+    }
+
+    /// Sets position of `part`, which has to be a slice of `source` (e.g. a line or field of the whole input)
+    pub fn locate(self, source: &str, part: &str) -> Self {
+        let source_range = source.as_bytes().as_ptr_range();
+        let part_range = part.as_bytes().as_ptr_range();
+        if part_range.start < source_range.start || part_range.end > source_range.end {
+            return self;
+        }
+        let offset = part_range.start as usize - source_range.start as usize;
+        let Some(before) = source.get(..offset) else {
+            return self;
+        };
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        self.at(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(Position { line, column }) = self.position {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        write!(f, "{}", self.reason)?;
+        if !self.text.is_empty() {
+            write!(f, " (`{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub trait Integer: FromStr<Err = ParseIntError> + TryFrom<u32> {}
+
+macro_rules! impl_integer {
+    ($($type:ty),*) => {
+        $(impl Integer for $type {})*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 pub trait StrParser {
-    fn parse_usize(&self) -> usize;
+    fn try_parse_int<T: Integer>(&self) -> Result<T, ParseError>;
+
+    fn try_parse_u8(&self) -> Result<u8, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_u16(&self) -> Result<u16, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_u32(&self) -> Result<u32, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_u64(&self) -> Result<u64, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_u128(&self) -> Result<u128, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_usize(&self) -> Result<usize, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_i8(&self) -> Result<i8, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_i16(&self) -> Result<i16, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_i32(&self) -> Result<i32, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_i64(&self) -> Result<i64, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_i128(&self) -> Result<i128, ParseError> {
+        self.try_parse_int()
+    }
+
+    fn try_parse_isize(&self) -> Result<isize, ParseError> {
+        self.try_parse_int()
+    }
 }
+
 impl StrParser for &str {
-    fn parse_usize(&self) -> usize {
-        self.parse::<usize>().expect("string is NOT number")
+    fn try_parse_int<T: Integer>(&self) -> Result<T, ParseError> {
+        self.parse::<T>().map_err(|error| {
+            ParseError::new(
+                *self,
+                format!("cannot parse {}: {}", type_name::<T>(), error),
+            )
+        })
     }
 }
 
 impl StrParser for char {
-    fn parse_usize(&self) -> usize {
-        self.to_digit(10).expect("Invalid character, not a digit") as usize
+    fn try_parse_int<T: Integer>(&self) -> Result<T, ParseError> {
+        self.to_digit(10)
+            .and_then(|digit| T::try_from(digit).ok())
+            .ok_or_else(|| ParseError::new(*self, "invalid character, not a digit"))
+    }
+}
+
+/// Parses `part` of `source` as an integer, the error is located within `source`
+pub fn parse_within<T: Integer>(source: &str, part: &str) -> Result<T, ParseError> {
+    part.try_parse_int()
+        .map_err(|error| error.locate(source, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_str() {
+        assert_eq!("42".try_parse_usize(), Ok(42));
+        assert_eq!("-42".try_parse_i32(), Ok(-42));
+        assert_eq!("255".try_parse_u8(), Ok(255));
+        assert!("256".try_parse_u8().is_err());
+        assert!("-1".try_parse_u64().is_err());
+    }
+
+    #[test]
+    fn parse_char() {
+        assert_eq!('7'.try_parse_u8(), Ok(7));
+        assert_eq!('7'.try_parse_i64(), Ok(7));
+        assert_eq!('x'.try_parse_usize().unwrap_err().text(), "x");
+    }
+
+    #[test]
+    fn locate_error() {
+        let input = "1 2\n3 x4\n";
+        let error = parse_within::<usize>(input, &input[6..8]).unwrap_err();
+        assert_eq!(error.position(), Some(Position { line: 2, column: 3 }));
+        assert_eq!(error.text(), "x4");
+        assert!(error.to_string().starts_with("line 2, column 3: "));
+    }
+
+    #[test]
+    fn locate_foreign_slice() {
+        let error = ParseError::new("x", "reason").locate("abc", "x");
+        assert_eq!(error.position(), None);
+        let whole = "ab\ncd";
+        let (source, rest) = whole.split_at(3);
+        let error = ParseError::new(rest, "reason").locate(source, rest);
+        assert_eq!(error.position(), None);
+        let error = ParseError::new("", "reason").locate(source, &source[3..]);
+        assert_eq!(error.position().map(|p| (p.line, p.column)), Some((2, 1)));
     }
 }
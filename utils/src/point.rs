@@ -0,0 +1,287 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Coord, Direction, Direction8, Turn};
+
+/// Signed number usable as a [`Point`] or [`Vector`] component
+pub trait Signed:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($type:ty),*) => {
+        $(impl Signed for $type {
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$type>::rem_euclid(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Position on an unbounded plane, unlike [`Coord`] it can go negative
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Difference of two [`Point`]s, `y` grows downwards like in [`Direction`]
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Vector<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Signed> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point<T>) -> T {
+        (other - self).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, other: Point<T>) -> T {
+        (other - self).chebyshev_length()
+    }
+
+    /// Wraps the point into the `width` x `height` area anchored at the origin, for planes that repeat
+    pub fn wrapped(self, width: T, height: T) -> Point<T> {
+        Point {
+            x: self.x.rem_euclid(width),
+            y: self.y.rem_euclid(height),
+        }
+    }
+}
+
+impl<T: Signed> Vector<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan_length(self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_length(self) -> T {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn rotate(self, turn: Turn) -> Vector<T> {
+        match turn {
+            Turn::Straight => self,
+            Turn::Left => Vector {
+                x: self.y,
+                y: -self.x,
+            },
+            Turn::TurnOver => -self,
+            Turn::Right => Vector {
+                x: -self.y,
+                y: self.x,
+            },
+        }
+    }
+}
+
+/// Smallest and largest corner of the rectangle covering all `points`
+pub fn bounding_box<T: Signed>(
+    points: impl IntoIterator<Item = Point<T>>,
+) -> Option<(Point<T>, Point<T>)> {
+    points.into_iter().fold(None, |bounds, point| match bounds {
+        None => Some((point, point)),
+        Some((min, max)) => Some((
+            Point::new(min.x.min(point.x), min.y.min(point.y)),
+            Point::new(max.x.max(point.x), max.y.max(point.y)),
+        )),
+    })
+}
+
+impl<T: Signed> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Point<T>) -> Self::Output {
+        Vector {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Signed> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Vector<T>) -> Self::Output {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Signed> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Signed> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Signed> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Signed> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: Vector<T>) -> Self::Output {
+        Vector {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Signed> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Signed> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Signed> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl From<Coord> for Point {
+    fn from(value: Coord) -> Self {
+        Point {
+            x: value.x as isize,
+            y: value.y as isize,
+        }
+    }
+}
+
+impl TryFrom<Point> for Coord {
+    type Error = ();
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        Ok(Coord {
+            x: value.x.try_into().map_err(|_| ())?,
+            y: value.y.try_into().map_err(|_| ())?,
+        })
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(value: Direction8) -> Self {
+        let (x, y) = value.offset();
+        Vector { x, y }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(value: Direction) -> Self {
+        Direction8::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        let diff = b - a;
+        assert_eq!(diff, Vector::new(3, -4));
+        assert_eq!(a + diff, b);
+        assert_eq!(a - diff, Point::new(-2, 6));
+        assert_eq!(diff * 2, Vector::new(6, -8));
+        assert_eq!(-diff, Vector::new(-3, 4));
+        assert_eq!(diff + diff - diff, diff);
+        let mut c = a;
+        c += diff;
+        c -= diff * 2;
+        assert_eq!(c, Point::new(-2, 6));
+    }
+
+    #[test]
+    fn distances() {
+        let a: Point<i64> = Point::new(-1, 5);
+        let b = Point::new(3, 2);
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn rotation_follows_direction() {
+        for direction in Direction::ALL {
+            for turn in [Turn::Straight, Turn::Left, Turn::TurnOver, Turn::Right] {
+                assert_eq!(
+                    Vector::from(direction).rotate(turn),
+                    Vector::from(direction + turn)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coord_conversion() {
+        let coord = Coord { x: 3, y: 7 };
+        let point = Point::from(coord);
+        assert_eq!(Coord::try_from(point), Ok(coord));
+        assert_eq!(Coord::try_from(point + Vector::new(-4, 0)), Err(()));
+    }
+
+    #[test]
+    fn infinite_plane() {
+        assert_eq!(Point::new(-1, 12).wrapped(10, 5), Point::new(9, 2));
+        assert_eq!(
+            bounding_box([Point::new(-3, 1), Point::new(2, -5), Point::new(0, 0)]),
+            Some((Point::new(-3, -5), Point::new(2, 1)))
+        );
+        assert_eq!(bounding_box(Vec::<Point>::new()), None);
+    }
+}
@@ -0,0 +1,329 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::graph::CycleError;
+
+/// Nodes reached from the start with their distance and the node they were reached from
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C = usize> {
+    pub distances: HashMap<N, C>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        SearchResult {
+            distances: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn is_reached(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.distances.keys()
+    }
+
+    /// Path from the start to `goal` (both included) following the predecessors
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.is_reached(goal) {
+            return None;
+        }
+        let mut path = vec![goal.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, distances are numbers of steps
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for next in successors(&node) {
+            if result.is_reached(&next) {
+                continue;
+            }
+            result.distances.insert(next.clone(), distance + 1);
+            result.predecessors.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+    result
+}
+
+/// Depth-first search, distances are depths in the search tree (not the shortest ones)
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut stack = vec![(start, None)];
+    while let Some((node, parent)) = stack.pop() {
+        if let Some((parent, distance)) = parent {
+            if result.is_reached(&node) {
+                continue;
+            }
+            result.distances.insert(node.clone(), distance + 1);
+            result.predecessors.insert(node.clone(), parent);
+        }
+        let distance = result.distances[&node];
+        for next in successors(&node) {
+            if !result.is_reached(&next) {
+                stack.push((next, Some((node.clone(), distance))));
+            }
+        }
+    }
+    result
+}
+
+/// Shortest distances for non-negative edge costs, `successors` yields `(node, cost)`
+pub fn dijkstra<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([(Reverse(C::default()), 0)]);
+    while let Some((Reverse(cost), index)) = heap.pop() {
+        let node = nodes[index].clone();
+        if result.distances[&node] < cost {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if result
+                .distance(&next)
+                .is_some_and(|known| known <= next_cost)
+            {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), node.clone());
+            heap.push((Reverse(next_cost), nodes.len()));
+            nodes.push(next);
+        }
+    }
+    result
+}
+
+/// Shortest path to the first goal found, `heuristic` must never overestimate the remaining cost
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+    let mut nodes = vec![start];
+    while let Some((_, Reverse(cost), index)) = heap.pop() {
+        let node = nodes[index].clone();
+        if result.distances[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((result.path_to(&node)?, cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if result
+                .distance(&next)
+                .is_some_and(|known| known <= next_cost)
+            {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), node.clone());
+            heap.push((
+                Reverse(next_cost + heuristic(&next)),
+                Reverse(next_cost),
+                nodes.len(),
+            ));
+            nodes.push(next);
+        }
+    }
+    None
+}
+
+/// Number of distinct paths from `start` to any goal, the graph has to be acyclic
+///
+/// Paths end at the first goal they reach. Nodes are visited in post-order on an explicit stack, so
+/// long chains do not overflow the call stack.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Result<usize, CycleError<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut known = HashMap::new();
+    let mut in_progress = HashSet::new();
+    // nodes of the current path with their successors left to visit and the paths counted so far
+    let mut stack: Vec<(N, I::IntoIter, usize)> = vec![];
+    let mut next = Some(start);
+    loop {
+        let paths = match next.take() {
+            Some(node) => match known.get(&node) {
+                Some(&paths) => paths,
+                None if is_goal(&node) => 1,
+                None => {
+                    if !in_progress.insert(node.clone()) {
+                        let position = stack
+                            .iter()
+                            .position(|(on_path, _, _)| *on_path == node)
+                            .expect("nodes in progress are on the stack");
+                        return Err(CycleError {
+                            nodes: stack
+                                .into_iter()
+                                .skip(position)
+                                .map(|(node, _, _)| node)
+                                .collect(),
+                        });
+                    }
+                    let node_successors = successors(&node).into_iter();
+                    stack.push((node, node_successors, 0));
+                    continue;
+                }
+            },
+            None => {
+                let (_, node_successors, _) =
+                    stack.last_mut().expect("the start node ends the loop");
+                if let Some(successor) = node_successors.next() {
+                    next = Some(successor);
+                    continue;
+                }
+                let (node, _, paths) = stack.pop().expect("checked above");
+                in_progress.remove(&node);
+                known.insert(node, paths);
+                paths
+            }
+        };
+        match stack.last_mut() {
+            Some((_, _, total)) => *total += paths,
+            None => return Ok(paths),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 -> 4
+    //  \-> 2 ---^
+    fn graph(node: &u8) -> Vec<u8> {
+        match node {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            3 => vec![4],
+            _ => vec![],
+        }
+    }
+
+    fn weighted(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(2, 1), (3, 7)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first() {
+        let result = bfs(0, graph);
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.distance(&4), Some(3));
+        assert_eq!(result.path_to(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(result.path_to(&5), None);
+        assert_eq!(result.reached().count(), 5);
+    }
+
+    #[test]
+    fn depth_first() {
+        let result = dfs(0, graph);
+        assert_eq!(result.reached().count(), 5);
+        assert_eq!(result.path_to(&0), Some(vec![0]));
+        assert_eq!(result.path_to(&4).map(|path| path.len()), Some(4));
+    }
+
+    #[test]
+    fn depth_first_follows_branch() {
+        // 0 -> 1 -> 3 -> 2, the edge 0 -> 2 is explored last
+        let diamond = |node: &u8| match node {
+            0 => vec![2, 1],
+            1 => vec![3],
+            3 => vec![2],
+            _ => vec![],
+        };
+        let result = dfs(0, diamond);
+        assert_eq!(result.path_to(&2), Some(vec![0, 1, 3, 2]));
+        assert_eq!(result.distance(&2), Some(3));
+        assert_eq!(bfs(0, diamond).path_to(&2), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn shortest_weighted() {
+        let result = dijkstra(0, weighted);
+        assert_eq!(result.distance(&2), Some(2));
+        assert_eq!(result.distance(&3), Some(3));
+        assert_eq!(result.path_to(&3), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn a_star() {
+        assert_eq!(
+            astar(0, weighted, |_| 0, |&node| node == 3),
+            Some((vec![0, 1, 2, 3], 3))
+        );
+        assert_eq!(astar(0, weighted, |_| 0, |&node| node == 9), None);
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(count_paths(0, graph, |&node| node == 4), Ok(2));
+        assert_eq!(count_paths(0, graph, |&node| node == 1), Ok(1));
+        assert_eq!(count_paths(4, graph, |&node| node == 0), Ok(0));
+        assert_eq!(count_paths(4, graph, |&node| node == 4), Ok(1));
+    }
+
+    #[test]
+    fn paths_with_cycle() {
+        let error = count_paths(0, |&node: &u8| [(node + 1) % 3], |&node| node == 5).unwrap_err();
+        assert_eq!(error.nodes, [0, 1, 2]);
+    }
+
+    #[test]
+    fn paths_along_long_chain() {
+        let length = 100_000;
+        let chain = |&node: &u32| (node < length).then_some(node + 1);
+        assert_eq!(count_paths(0, chain, |&node| node == length), Ok(1));
+    }
+}
@@ -0,0 +1,174 @@
+use std::any::Any;
+use std::env;
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::process;
+
+use crate::input::InputSource;
+use crate::parser::ParseError;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    First,
+    Second,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::First, Part::Second];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::First => 1,
+            Part::Second => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::First),
+            2 => Ok(Part::Second),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Answer of a part as it is printed, parts that can fail on a valid input return a `Result`
+pub trait ToAnswer {
+    fn to_answer(&self) -> Result<String, String>;
+}
+
+macro_rules! impl_to_answer {
+    ($($type:ty),*) => {
+        $(impl ToAnswer for $type {
+            fn to_answer(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_to_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
+impl<T: Display, E: Display> ToAnswer for Result<T, E> {
+    fn to_answer(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
+
+/// Puzzle of a single day: the input is parsed once and both parts are solved from the parsed model.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type FirstAnswer: ToAnswer;
+    type SecondAnswer: ToAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn first_part(input: &Self::Input) -> Self::FirstAnswer;
+    fn second_part(input: &Self::Input) -> Self::SecondAnswer;
+
+    /// Lines telling how the answer of `part` was found, empty for days without explanations
+    fn explain(_input: &Self::Input, _part: Part) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn solve_first_part(input: &str) -> Result<Self::FirstAnswer, ParseError> {
+        Ok(Self::first_part(&Self::parse(input)?))
+    }
+
+    fn solve_second_part(input: &str) -> Result<Self::SecondAnswer, ParseError> {
+        Ok(Self::second_part(&Self::parse(input)?))
+    }
+}
+
+/// Object safe view of a [`Solution`], so days with different input and answer types can be stored together.
+pub trait AnySolution: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError>;
+    /// Printed answer, or why the part cannot be solved
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String, String>;
+    fn explain(&self, input: &dyn Any, part: Part) -> Vec<String>;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String, String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        match part {
+            Part::First => S::first_part(input).to_answer(),
+            Part::Second => S::second_part(input).to_answer(),
+        }
+    }
+
+    fn explain(&self, input: &dyn Any, part: Part) -> Vec<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        S::explain(input, part)
+    }
+}
+
+/// Solves both parts of the input given by the first argument (see [`InputSource::resolve`]) and prints the answers, used by the `main` of every day.
+pub fn run<S: Solution>(crate_dir: &str) {
+    let argument = env::args().nth(1);
+    let source = InputSource::resolve(S::DAY, argument.as_deref(), Path::new(crate_dir));
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    let input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Invalid input: {}", error);
+            process::exit(1);
+        }
+    };
+    let answers = [
+        ("First", S::first_part(&input).to_answer()),
+        ("Second", S::second_part(&input).to_answer()),
+    ];
+    for (part, answer) in answers {
+        match answer {
+            Ok(answer) => println!("{} part: {}", part, answer),
+            Err(error) => {
+                eprintln!("{} part failed: {}", part, error);
+                process::exit(1);
+            }
+        }
+    }
+}